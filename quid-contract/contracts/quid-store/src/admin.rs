@@ -0,0 +1,105 @@
+use soroban_sdk::{Address, Env};
+
+use crate::error::QuidError;
+use crate::index;
+use crate::types::{Config, DataKey, IndexKey};
+
+pub fn admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .expect("contract not initialized")
+}
+
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+/// Loads the admin and requires its authorization.
+pub fn require_admin(env: &Env) -> Address {
+    let admin = admin(env);
+    admin.require_auth();
+    admin
+}
+
+pub fn pending_admin(env: &Env) -> Result<Address, QuidError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PendingAdmin)
+        .ok_or(QuidError::NoPendingAdmin)
+}
+
+pub fn set_pending_admin(env: &Env, pending: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingAdmin, pending);
+}
+
+pub fn clear_pending_admin(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+}
+
+pub fn config(env: &Env) -> Config {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config)
+        .expect("contract not initialized")
+}
+
+pub fn set_config(env: &Env, config: &Config) {
+    env.storage().instance().set(&DataKey::Config, config);
+}
+
+pub fn is_paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Paused)
+        .unwrap_or(false)
+}
+
+pub fn set_paused(env: &Env, paused: bool) {
+    env.storage().instance().set(&DataKey::Paused, &paused);
+}
+
+pub fn require_not_paused(env: &Env) -> Result<(), QuidError> {
+    if is_paused(env) {
+        return Err(QuidError::ContractPaused);
+    }
+    Ok(())
+}
+
+/// `Some(true)` for an allowed token, `Some(false)` for one that was de-listed and
+/// `None` for a token that was never listed.
+fn token_flag(env: &Env, token: &Address) -> Option<bool> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AllowedToken(token.clone()))
+}
+
+pub fn is_token_allowed(env: &Env, token: &Address) -> bool {
+    token_flag(env, token).unwrap_or(false)
+}
+
+/// Allows or de-lists `token`. A token is added to the `AllowedTokens` index the first
+/// time it is listed; later changes only flip its flag.
+pub fn set_token_allowed(env: &Env, token: &Address, allowed: bool) {
+    match token_flag(env, token) {
+        None if !allowed => return,
+        None => {
+            index::push(env, &IndexKey::AllowedTokens, token.clone());
+        }
+        Some(_) => {}
+    }
+    let key = DataKey::AllowedToken(token.clone());
+    env.storage().persistent().set(&key, &allowed);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, 5184000, 5184000);
+}
+
+pub fn require_allowed_token(env: &Env, token: &Address) -> Result<(), QuidError> {
+    if !is_token_allowed(env, token) {
+        return Err(QuidError::TokenNotAllowed);
+    }
+    Ok(())
+}
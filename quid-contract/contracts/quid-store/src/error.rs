@@ -16,4 +16,18 @@ pub enum QuidError {
     SubmissionNotFound = 11,
     NotPending = 12,
     InvalidAmount = 13,
+    StakeNotFound = 14,
+    NotApproved = 15,
+    InvalidDeadline = 16,
+    SubmissionDeadlinePassed = 17,
+    DeadlineNotReached = 18,
+    NotEligible = 19,
+    InsufficientReputation = 20,
+    StakeTokenMismatch = 21,
+    StakeTooLow = 22,
+    InvalidPrizeTable = 23,
+    WrongMissionKind = 24,
+    NoPendingAdmin = 25,
+    ContractPaused = 26,
+    TokenNotAllowed = 27,
 }
@@ -0,0 +1,32 @@
+use soroban_sdk::{token, Address, Env};
+
+use crate::types::DataKey;
+
+/// Pulls `amount` of `token` from `from` into the contract and records it as owed.
+pub fn deposit(env: &Env, token: &Address, from: &Address, amount: i128) {
+    token::Client::new(env, token).transfer(from, env.current_contract_address(), &amount);
+    adjust_liabilities(env, token, amount);
+}
+
+/// Sends `amount` of `token` from the contract to `to` and removes it from what is owed.
+pub fn withdraw(env: &Env, token: &Address, to: &Address, amount: i128) {
+    token::Client::new(env, token).transfer(&env.current_contract_address(), to, &amount);
+    adjust_liabilities(env, token, -amount);
+}
+
+/// Total amount of `token` the contract owes to founders and hunters.
+pub fn liabilities(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Liabilities(token.clone()))
+        .unwrap_or(0)
+}
+
+fn adjust_liabilities(env: &Env, token: &Address, delta: i128) {
+    let key = DataKey::Liabilities(token.clone());
+    let total = liabilities(env, token) + delta;
+    env.storage().persistent().set(&key, &total);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, 5184000, 5184000);
+}
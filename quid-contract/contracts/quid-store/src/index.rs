@@ -0,0 +1,104 @@
+use soroban_sdk::{Env, IntoVal, TryFromVal, Val, Vec};
+
+use crate::types::{DataKey, IndexKey};
+
+/// Upper bound on the number of entries a single list query returns.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Entries per storage bucket. Lists only ever grow by appending whole new buckets, so no
+/// single ledger entry grows with the list.
+const BUCKET_SIZE: u32 = 50;
+
+/// Marks a removed id. Mission ids start at 1, so 0 is never a real entry.
+const REMOVED: u64 = 0;
+
+/// Number of positions used in the list, including removed entries.
+pub fn len(env: &Env, list: &IndexKey) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::IndexLen(list.clone()))
+        .unwrap_or(0)
+}
+
+/// Appends `item` to the list and returns its position.
+pub fn push<T>(env: &Env, list: &IndexKey, item: T) -> u32
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let pos = len(env, list);
+    let bucket = pos / BUCKET_SIZE;
+    let mut items: Vec<T> = load_bucket(env, list, bucket);
+    items.push_back(item);
+    save(env, &DataKey::IndexBucket(list.clone(), bucket), &items);
+    save(env, &DataKey::IndexLen(list.clone()), &(pos + 1));
+    pos
+}
+
+/// Removes the id at `pos` from an id list, leaving a gap so later positions don't shift.
+pub fn remove_id(env: &Env, list: &IndexKey, pos: u32) {
+    let bucket = pos / BUCKET_SIZE;
+    let mut ids: Vec<u64> = load_bucket(env, list, bucket);
+    if pos % BUCKET_SIZE < ids.len() {
+        ids.set(pos % BUCKET_SIZE, REMOVED);
+        save(env, &DataKey::IndexBucket(list.clone(), bucket), &ids);
+    }
+}
+
+/// Returns the entries at positions `cursor` up to `cursor + limit` (capped at
+/// `MAX_PAGE_SIZE`). Removed ids are left in as `0`; see `live_ids`.
+pub fn page<T>(env: &Env, list: &IndexKey, cursor: u32, limit: u32) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let end = cursor
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(len(env, list));
+
+    let mut items = Vec::new(env);
+    let mut pos = cursor;
+    while pos < end {
+        let bucket = pos / BUCKET_SIZE;
+        let bucket_items: Vec<T> = load_bucket(env, list, bucket);
+        let stop = end.min((bucket + 1) * BUCKET_SIZE);
+        items.append(&bucket_items.slice(pos % BUCKET_SIZE..stop - bucket * BUCKET_SIZE));
+        pos = stop;
+    }
+    items
+}
+
+/// Drops the gaps left by `remove_id` from a page of ids.
+pub fn live_ids(env: &Env, ids: &Vec<u64>) -> Vec<u64> {
+    let mut live = Vec::new(env);
+    for id in ids.iter() {
+        if id != REMOVED {
+            live.push_back(id);
+        }
+    }
+    live
+}
+
+/// Where the page after one that started at `cursor` and read `read` entries begins,
+/// or `None` if the list has been read to the end.
+pub fn next_cursor(env: &Env, list: &IndexKey, cursor: u32, read: u32) -> Option<u32> {
+    let end = cursor.saturating_add(read);
+    if end < len(env, list) {
+        Some(end)
+    } else {
+        None
+    }
+}
+
+fn load_bucket<T>(env: &Env, list: &IndexKey, bucket: u32) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    env.storage()
+        .persistent()
+        .get(&DataKey::IndexBucket(list.clone(), bucket))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn save<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage().persistent().extend_ttl(key, 5184000, 5184000);
+}
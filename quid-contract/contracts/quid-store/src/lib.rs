@@ -1,12 +1,26 @@
 #![no_std]
-use soroban_sdk::{contract, contractevent, contractimpl, Address, Env, String};
+use soroban_sdk::{contract, contractevent, contractimpl, Address, BytesN, Env, String, Vec};
 
+mod admin;
 mod error;
+mod escrow;
+mod index;
+mod migration;
+mod reputation;
 mod types;
 
 use error::QuidError;
 use soroban_sdk::token;
-use types::{DataKey, Mission, MissionStatus, Submission, SubmissionStatus};
+use types::{
+    Config, DataKey, ExpiryPolicy, FounderStats, HunterProfile, IndexKey, Mission, MissionKind,
+    MissionOptions, MissionPage, MissionStatus, PayoutOutcome, PayoutResult, RewardComponent,
+    SlotPolicy, SolvencyReport, Stake, Submission, SubmissionPage, SubmissionStatus, TokenPage,
+};
+
+/// Basis points in a whole reward.
+const BPS_DENOMINATOR: u32 = 10_000;
+/// Highest platform fee the admin can set: 10% of each reward.
+const MAX_FEE_BPS: u32 = 1_000;
 
 #[contractevent(topics = ["mission", "create"])]
 pub struct MissionCreateEvent {
@@ -20,10 +34,33 @@ pub struct SubNewEvent {
     pub hunter: Address,
 }
 
+#[contractevent(topics = ["sub", "reject"])]
+pub struct SubRejectEvent {
+    pub mission_id: u64,
+    pub hunter: Address,
+    pub reason_cid: String,
+}
+
+#[contractevent(topics = ["sub", "approve"])]
+pub struct SubApproveEvent {
+    pub mission_id: u64,
+    pub hunter: Address,
+    pub amount: i128,
+}
+
 #[contractevent(topics = ["payout", "done"])]
 pub struct PayoutDoneEvent {
     pub mission_id: u64,
     pub hunter: Address,
+    pub amount: i128,
+    pub fee: i128,
+}
+
+#[contractevent(topics = ["payout", "bonus"])]
+pub struct PayoutBonusEvent {
+    pub mission_id: u64,
+    pub hunter: Address,
+    pub amount: i128,
 }
 
 #[contractevent(topics = ["mission", "cancel"], data_format = "single-value")]
@@ -36,12 +73,227 @@ pub struct MissionPauseEvent {
     pub mission_id: u64,
 }
 
+#[contractevent(topics = ["stake", "return"])]
+pub struct StakeReturnEvent {
+    pub mission_id: u64,
+    pub hunter: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["stake", "slash"])]
+pub struct StakeSlashEvent {
+    pub mission_id: u64,
+    pub hunter: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["mission", "resume"], data_format = "single-value")]
+pub struct MissionResumeEvent {
+    pub mission_id: u64,
+}
+
+#[contractevent(topics = ["mission", "expire"], data_format = "single-value")]
+pub struct MissionExpireEvent {
+    pub mission_id: u64,
+}
+
+#[contractevent(topics = ["mission", "budget"])]
+pub struct MissionBudgetEvent {
+    pub mission_id: u64,
+    pub reward_amount: i128,
+    pub max_participants: u32,
+}
+
+#[contractevent(topics = ["mission", "status"])]
+pub struct MissionStatusEvent {
+    pub mission_id: u64,
+    pub from: MissionStatus,
+    pub to: MissionStatus,
+}
+
+#[contractevent(topics = ["admin", "transfer"])]
+pub struct AdminTransferEvent {
+    pub admin: Address,
+    pub pending_admin: Address,
+}
+
+#[contractevent(topics = ["admin", "accept"], data_format = "single-value")]
+pub struct AdminAcceptEvent {
+    pub admin: Address,
+}
+
+#[contractevent(topics = ["contract", "migrate"], data_format = "single-value")]
+pub struct MigrationDoneEvent {
+    pub version: u32,
+}
+
+#[contractevent(topics = ["contract", "pause"], data_format = "single-value")]
+pub struct ContractPauseEvent {
+    pub paused: bool,
+}
+
 #[contract]
 pub struct QuidStoreContract;
 
 #[contractimpl]
 impl QuidStoreContract {
+    /// Stores the admin and a default config with the admin as treasury.
+    pub fn __constructor(env: Env, admin: Address) {
+        admin::set_admin(&env, &admin);
+        admin::set_config(
+            &env,
+            &Config {
+                treasury: admin,
+                fee_bps: 0,
+            },
+        );
+        migration::set_schema_version(&env, migration::SCHEMA_VERSION);
+    }
+
+    pub fn get_admin(env: Env) -> Address {
+        admin::admin(&env)
+    }
+
+    /// Propose a new admin. The handover completes once they call `accept_admin`.
+    pub fn transfer_admin(env: Env, new_admin: Address) {
+        let admin = admin::require_admin(&env);
+        admin::set_pending_admin(&env, &new_admin);
+
+        AdminTransferEvent {
+            admin,
+            pending_admin: new_admin,
+        }
+        .publish(&env);
+    }
+
+    pub fn accept_admin(env: Env) -> Result<(), QuidError> {
+        let pending = admin::pending_admin(&env)?;
+        pending.require_auth();
+
+        admin::set_admin(&env, &pending);
+        admin::clear_pending_admin(&env);
+
+        AdminAcceptEvent { admin: pending }.publish(&env);
+        Ok(())
+    }
+
+    /// Emergency stop: while paused no missions can be created, submitted to or paid out.
+    /// Owners can still cancel missions, anyone can still expire `RefundPending` ones and
+    /// hunters can still withdraw their stakes.
+    pub fn set_paused(env: Env, paused: bool) {
+        admin::require_admin(&env);
+        admin::set_paused(&env, paused);
+
+        ContractPauseEvent { paused }.publish(&env);
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        admin::is_paused(&env)
+    }
+
+    /// Allow `token` to be used for rewards and stakes on new missions.
+    pub fn add_allowed_token(env: Env, token: Address) {
+        admin::require_admin(&env);
+        admin::set_token_allowed(&env, &token, true);
+    }
+
+    /// Stop accepting `token` for new missions and submissions. Funds already held
+    /// in it can still be paid out and refunded.
+    pub fn remove_allowed_token(env: Env, token: Address) {
+        admin::require_admin(&env);
+        admin::set_token_allowed(&env, &token, false);
+    }
+
+    /// List allowed tokens in the order they were first listed. Works like
+    /// `list_missions_by_status`: de-listed tokens are skipped, so continue from
+    /// `next_cursor` until it is `None`.
+    pub fn list_allowed_tokens(env: Env, cursor: u32, limit: u32) -> TokenPage {
+        let list = IndexKey::AllowedTokens;
+        let listed: Vec<Address> = index::page(&env, &list, cursor, limit);
+
+        let mut tokens = Vec::new(&env);
+        for token in listed.iter() {
+            if admin::is_token_allowed(&env, &token) {
+                tokens.push_back(token);
+            }
+        }
+        TokenPage {
+            tokens,
+            next_cursor: index::next_cursor(&env, &list, cursor, listed.len()),
+        }
+    }
+
+    /// Replace the contract code. Storage is kept; call `migrate` afterwards if the new
+    /// code bumps the schema version.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        admin::require_admin(&env);
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    pub fn get_schema_version(env: Env) -> u32 {
+        migration::schema_version(&env)
+    }
+
+    /// Rewrite up to `limit` more mission and submission records in the current layout,
+    /// resuming where the previous call stopped, even partway through a mission. Returns
+    /// the number of records handled; the schema version is bumped once every mission
+    /// has been migrated.
+    pub fn migrate(env: Env, limit: u32) -> Result<u32, QuidError> {
+        admin::require_admin(&env);
+        if migration::schema_version(&env) >= migration::SCHEMA_VERSION {
+            return Err(QuidError::InvalidState);
+        }
+
+        let budget = limit.min(index::MAX_PAGE_SIZE);
+        let count = Self::get_mission_count(env.clone());
+        let mut progress = migration::cursor(&env);
+        let mut handled = 0;
+        while progress.mission_id <= count && handled < budget {
+            let (done, next) = migration::migrate_mission(
+                &env,
+                progress.mission_id,
+                progress.submission_pos,
+                budget - handled,
+            );
+            handled += done;
+            match next {
+                Some(pos) => progress.submission_pos = pos,
+                None => {
+                    progress.mission_id += 1;
+                    progress.submission_pos = 0;
+                }
+            }
+        }
+
+        if progress.mission_id <= count {
+            migration::set_cursor(&env, &progress);
+        } else {
+            migration::clear_cursor(&env);
+            migration::set_schema_version(&env, migration::SCHEMA_VERSION);
+            MigrationDoneEvent {
+                version: migration::SCHEMA_VERSION,
+            }
+            .publish(&env);
+        }
+        Ok(handled)
+    }
+
+    pub fn get_config(env: Env) -> Config {
+        admin::config(&env)
+    }
+
+    /// Fee changes only apply to missions created afterwards.
+    pub fn set_config(env: Env, config: Config) -> Result<(), QuidError> {
+        admin::require_admin(&env);
+        if config.fee_bps > MAX_FEE_BPS {
+            return Err(QuidError::InvalidAmount);
+        }
+        admin::set_config(&env, &config);
+        Ok(())
+    }
+
     /// Create mission
+    #[allow(clippy::too_many_arguments)]
     pub fn create_mission(
         env: Env,
         owner: Address,
@@ -50,17 +302,53 @@ impl QuidStoreContract {
         reward_token: Address,
         reward_amount: i128,
         max_participants: u32,
+        options: MissionOptions,
     ) -> Result<u64, QuidError> {
+        admin::require_not_paused(&env)?;
         owner.require_auth();
 
         Self::validate_mission_params(&title, reward_amount)?;
+        Self::validate_deadlines(&env, &options)?;
+        for rule in options.gating.iter() {
+            if rule.min_balance <= 0 {
+                return Err(QuidError::InvalidAmount);
+            }
+        }
+        if options.min_stake < 0 {
+            return Err(QuidError::InvalidAmount);
+        }
+        if !options.extra_rewards.is_empty() {
+            if let MissionKind::Contest(_) = options.kind {
+                return Err(QuidError::WrongMissionKind);
+            }
+        }
+        for extra in options.extra_rewards.iter() {
+            if extra.amount <= 0 {
+                return Err(QuidError::InvalidAmount);
+            }
+        }
+        let stake_token = options.stake_token.unwrap_or(reward_token.clone());
+        admin::require_allowed_token(&env, &reward_token)?;
+        admin::require_allowed_token(&env, &stake_token)?;
+        for extra in options.extra_rewards.iter() {
+            admin::require_allowed_token(&env, &extra.token)?;
+        }
 
-        let total_needed: i128 = reward_amount
-            .checked_mul(max_participants as i128)
-            .ok_or(QuidError::NegativeReward)?;
+        let (total_needed, slot_policy) = match &options.kind {
+            MissionKind::Bounty => (
+                reward_amount
+                    .checked_mul(max_participants as i128)
+                    .ok_or(QuidError::NegativeReward)?,
+                options.slot_policy,
+            ),
+            MissionKind::Contest(prizes) => (
+                Self::validate_prize_table(prizes, reward_amount, max_participants)?,
+                SlotPolicy::Competition,
+            ),
+        };
 
-        let token_client = token::Client::new(&env, &reward_token);
-        token_client.transfer(&owner, env.current_contract_address(), &total_needed);
+        escrow::deposit(&env, &reward_token, &owner, total_needed);
+        Self::deposit_extra_rewards(&env, &options.extra_rewards, &owner, max_participants)?;
 
         let mission_id = Self::get_next_mission_id(&env);
 
@@ -75,8 +363,23 @@ impl QuidStoreContract {
             reward_amount,
             max_participants,
             participants_count: 0,
+            submissions_count: 0,
+            slot_policy,
+            submission_deadline: options.submission_deadline,
+            review_deadline: options.review_deadline,
+            expiry_policy: options.expiry_policy,
+            gating: options.gating,
+            min_reputation: options.min_reputation,
+            stake_token,
+            min_stake: options.min_stake,
+            kind: options.kind,
+            extra_rewards: options.extra_rewards,
+            fee_bps: admin::config(&env).fee_bps,
+            escrow_balance: total_needed,
+            earmarked: 0,
             status: MissionStatus::Open,
             created_at,
+            paused_from: MissionStatus::Open,
         };
 
         env.storage()
@@ -87,6 +390,10 @@ impl QuidStoreContract {
             .persistent()
             .extend_ttl(&DataKey::Mission(mission_id), 5184000, 5184000);
 
+        Self::index_status(&env, mission_id, MissionStatus::Open);
+        index::push(&env, &IndexKey::OwnerMissions(owner.clone()), mission_id);
+        reputation::record_mission_created(&env, &owner);
+
         MissionCreateEvent { mission_id, owner }.publish(&env);
 
         Ok(mission_id)
@@ -109,14 +416,31 @@ impl QuidStoreContract {
         stake_token: Address,
         stake_amount: i128,
     ) -> Result<(), QuidError> {
+        admin::require_not_paused(&env)?;
         hunter.require_auth();
 
-        let mission = Self::get_mission(env.clone(), mission_id)?;
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
 
         if mission.status != MissionStatus::Open && mission.status != MissionStatus::Started {
             return Err(QuidError::MissionNotOpen);
         }
-        if mission.participants_count >= mission.max_participants {
+        // Work arriving after the review deadline could never be reviewed, so it is refused
+        // even when the mission has no submission deadline of its own.
+        let now = env.ledger().timestamp();
+        if mission
+            .submission_deadline
+            .is_some_and(|deadline| now > deadline)
+            || mission
+                .review_deadline
+                .is_some_and(|deadline| now > deadline)
+        {
+            return Err(QuidError::SubmissionDeadlinePassed);
+        }
+        let taken_slots = match mission.slot_policy {
+            SlotPolicy::FirstCome => mission.submissions_count,
+            SlotPolicy::Competition => mission.participants_count,
+        };
+        if taken_slots >= mission.max_participants {
             return Err(QuidError::MissionFull);
         }
 
@@ -126,24 +450,54 @@ impl QuidStoreContract {
             return Err(QuidError::AlreadySubmitted);
         }
 
-        if stake_amount <= 0 {
+        if stake_token != mission.stake_token {
+            return Err(QuidError::StakeTokenMismatch);
+        }
+        if mission.min_stake > 0 {
+            if stake_amount <= 0 {
+                return Err(QuidError::InvalidAmount);
+            }
+            if stake_amount < mission.min_stake {
+                return Err(QuidError::StakeTooLow);
+            }
+            admin::require_allowed_token(&env, &stake_token)?;
+        } else if stake_amount != 0 {
             return Err(QuidError::InvalidAmount);
         }
 
-        let token_client = token::Client::new(&env, &stake_token);
-        token_client.transfer(&hunter, env.current_contract_address(), &stake_amount);
+        if reputation::hunter_profile(&env, &hunter).paid_count < mission.min_reputation {
+            return Err(QuidError::InsufficientReputation);
+        }
 
-        let stake_key = DataKey::HunterStake(mission_id, hunter.clone());
-        env.storage().persistent().set(&stake_key, &stake_amount);
-        env.storage()
-            .persistent()
-            .extend_ttl(&stake_key, 5184000, 5184000);
+        for rule in mission.gating.iter() {
+            let balance = token::Client::new(&env, &rule.token).balance(&hunter);
+            if balance < rule.min_balance {
+                return Err(QuidError::NotEligible);
+            }
+        }
+
+        if stake_amount > 0 {
+            escrow::deposit(&env, &stake_token, &hunter, stake_amount);
+
+            let stake_key = DataKey::HunterStake(mission_id, hunter.clone());
+            let stake = Stake {
+                token: stake_token,
+                amount: stake_amount,
+            };
+            env.storage().persistent().set(&stake_key, &stake);
+            env.storage()
+                .persistent()
+                .extend_ttl(&stake_key, 5184000, 5184000);
+        }
 
         let submission = Submission {
             hunter: hunter.clone(),
             ipfs_cid,
             status: SubmissionStatus::Pending,
             submitted_at: env.ledger().timestamp(),
+            rejection_cid: None,
+            reward_amount: 0,
+            bonus: 0,
         };
 
         env.storage().persistent().set(&key, &submission);
@@ -151,6 +505,22 @@ impl QuidStoreContract {
             .persistent()
             .extend_ttl(&key, 5184000, 5184000);
 
+        index::push(
+            &env,
+            &IndexKey::MissionSubmissions(mission_id),
+            hunter.clone(),
+        );
+        index::push(
+            &env,
+            &IndexKey::HunterSubmissions(hunter.clone()),
+            mission_id,
+        );
+
+        mission.submissions_count += 1;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Mission(mission_id), &mission);
+
         SubNewEvent { mission_id, hunter }.publish(&env);
 
         Ok(())
@@ -167,7 +537,7 @@ impl QuidStoreContract {
 
         let mission = Self::get_mission(env.clone(), mission_id)?;
 
-        if mission.status != MissionStatus::Open {
+        if mission.status != MissionStatus::Open && mission.status != MissionStatus::Started {
             return Err(QuidError::MissionNotOpen);
         }
 
@@ -186,12 +556,19 @@ impl QuidStoreContract {
         if submission.status == SubmissionStatus::Paid {
             return Err(QuidError::AlreadyPaid);
         }
+        // Approved work is what the owner reviewed, so it can't change before it is claimed.
+        if submission.status != SubmissionStatus::Pending {
+            return Err(QuidError::NotPending);
+        }
 
         let updated_submission = Submission {
             hunter: submission.hunter,
             ipfs_cid: new_ipfs_cid,
             status: submission.status,
             submitted_at: submission.submitted_at,
+            rejection_cid: submission.rejection_cid,
+            reward_amount: submission.reward_amount,
+            bonus: submission.bonus,
         };
 
         env.storage().persistent().set(&key, &updated_submission);
@@ -202,138 +579,1081 @@ impl QuidStoreContract {
         Ok(())
     }
 
-    /// Payout Participant
-    pub fn payout_participant(env: Env, mission_id: u64, hunter: Address) -> Result<(), QuidError> {
+    /// Payout Participant. `amount` defaults to the mission's `reward_amount`, which is also
+    /// the most a single submission can be awarded; anything not awarded goes back to the owner.
+    pub fn payout_participant(
+        env: Env,
+        mission_id: u64,
+        hunter: Address,
+        amount: Option<i128>,
+    ) -> Result<(), QuidError> {
+        admin::require_not_paused(&env)?;
         let mut mission = Self::get_mission(env.clone(), mission_id)?;
         mission.owner.require_auth();
 
-        if matches!(
-            mission.status,
-            MissionStatus::Completed | MissionStatus::Cancelled
-        ) {
+        if mission.status.is_closed() {
             return Err(QuidError::MissionClosed);
         }
 
-        let key = DataKey::Submission(mission_id, hunter.clone());
-        let mut submission: Submission = env
-            .storage()
+        Self::require_bounty(&mission)?;
+        let amount = Self::resolve_reward(&mission, amount)?;
+        Self::payout(&env, &mut mission, &hunter, amount)?;
+        env.storage()
             .persistent()
-            .get(&key)
-            .ok_or(QuidError::SubmissionNotFound)?;
+            .set(&DataKey::Mission(mission_id), &mission);
 
-        if submission.status == SubmissionStatus::Paid {
-            return Err(QuidError::AlreadyPaid);
+        Ok(())
+    }
+
+    /// Pay several pending submissions with a single owner signature. Hunters that can't be
+    /// paid are skipped and reported instead of failing the whole batch.
+    pub fn batch_payout(
+        env: Env,
+        mission_id: u64,
+        hunters: Vec<Address>,
+    ) -> Result<Vec<PayoutResult>, QuidError> {
+        admin::require_not_paused(&env)?;
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        mission.owner.require_auth();
+
+        if mission.status.is_closed() {
+            return Err(QuidError::MissionClosed);
         }
-        if submission.status != SubmissionStatus::Pending {
-            return Err(QuidError::NotPending);
+
+        Self::require_bounty(&mission)?;
+
+        let amount = mission.reward_amount;
+        let mut results = Vec::new(&env);
+        for hunter in hunters.iter() {
+            let outcome = if mission.status.is_closed() {
+                PayoutOutcome::MissionClosed
+            } else {
+                match Self::payout(&env, &mut mission, &hunter, amount) {
+                    Ok(()) => PayoutOutcome::Paid,
+                    Err(QuidError::AlreadyPaid) => PayoutOutcome::AlreadyPaid,
+                    Err(QuidError::NotPending) => PayoutOutcome::NotPending,
+                    Err(QuidError::SubmissionNotFound) => PayoutOutcome::SubmissionNotFound,
+                    Err(err) => return Err(err),
+                }
+            };
+            results.push_back(PayoutResult { hunter, outcome });
         }
 
-        let token_client = token::Client::new(&env, &mission.reward_token);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &hunter,
-            &mission.reward_amount,
-        );
+        env.storage()
+            .persistent()
+            .set(&DataKey::Mission(mission_id), &mission);
 
-        submission.status = SubmissionStatus::Paid;
-        env.storage().persistent().set(&key, &submission);
+        Ok(results)
+    }
 
-        mission.participants_count += 1;
-        if mission.max_participants > 0 && mission.participants_count >= mission.max_participants {
-            mission.status = MissionStatus::Completed;
+    /// Approve a pending submission, earmarking its reward for the hunter to claim.
+    /// `amount` works as in `payout_participant`.
+    pub fn approve_submission(
+        env: Env,
+        mission_id: u64,
+        hunter: Address,
+        amount: Option<i128>,
+    ) -> Result<(), QuidError> {
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        mission.owner.require_auth();
+
+        if mission.status.is_closed() {
+            return Err(QuidError::MissionClosed);
         }
+
+        Self::require_bounty(&mission)?;
+        let amount = Self::resolve_reward(&mission, amount)?;
+        Self::approve_pending(&env, &mut mission, &hunter, amount)?;
+
         env.storage()
             .persistent()
             .set(&DataKey::Mission(mission_id), &mission);
 
-        PayoutDoneEvent { mission_id, hunter }.publish(&env);
-
         Ok(())
     }
 
-    pub fn cancel_mission(env: Env, mission_id: u64) -> Result<(), QuidError> {
+    /// Approve several pending submissions at the full reward in one call, reporting what
+    /// happened to each hunter instead of failing the whole batch.
+    pub fn batch_approve(
+        env: Env,
+        mission_id: u64,
+        hunters: Vec<Address>,
+    ) -> Result<Vec<PayoutResult>, QuidError> {
         let mut mission = Self::get_mission(env.clone(), mission_id)?;
         mission.owner.require_auth();
 
-        if matches!(
-            mission.status,
-            MissionStatus::Cancelled | MissionStatus::Completed
-        ) {
+        if mission.status.is_closed() {
             return Err(QuidError::MissionClosed);
         }
 
-        let remaining_slots = mission
-            .max_participants
-            .saturating_sub(mission.participants_count);
-        let refund_amount: i128 = (remaining_slots as i128)
-            .checked_mul(mission.reward_amount)
-            .ok_or(QuidError::NegativeReward)?;
-
-        if refund_amount > 0 {
-            let token_client = token::Client::new(&env, &mission.reward_token);
-            token_client.transfer(
-                &env.current_contract_address(),
-                &mission.owner,
-                &refund_amount,
-            );
+        Self::require_bounty(&mission)?;
+
+        let amount = mission.reward_amount;
+        let mut results = Vec::new(&env);
+        for hunter in hunters.iter() {
+            let outcome = if mission.status.is_closed() {
+                PayoutOutcome::MissionClosed
+            } else {
+                match Self::approve_pending(&env, &mut mission, &hunter, amount) {
+                    Ok(()) => PayoutOutcome::Approved,
+                    Err(QuidError::AlreadyPaid) => PayoutOutcome::AlreadyPaid,
+                    Err(QuidError::NotPending) => PayoutOutcome::NotPending,
+                    Err(QuidError::SubmissionNotFound) => PayoutOutcome::SubmissionNotFound,
+                    Err(err) => return Err(err),
+                }
+            };
+            results.push_back(PayoutResult { hunter, outcome });
         }
 
-        mission.status = MissionStatus::Cancelled;
         env.storage()
             .persistent()
             .set(&DataKey::Mission(mission_id), &mission);
 
-        MissionCancelEvent { mission_id }.publish(&env);
+        Ok(results)
+    }
 
-        Ok(())
+    /// Claim an approved reward. Earmarked rewards stay claimable after the mission closes.
+    pub fn claim_reward(env: Env, mission_id: u64, hunter: Address) -> Result<i128, QuidError> {
+        admin::require_not_paused(&env)?;
+        hunter.require_auth();
+
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+
+        let key = DataKey::Submission(mission_id, hunter.clone());
+        let mut submission: Submission = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(QuidError::SubmissionNotFound)?;
+
+        if submission.status == SubmissionStatus::Paid {
+            return Err(QuidError::AlreadyPaid);
+        }
+        if submission.status != SubmissionStatus::Approved {
+            return Err(QuidError::NotApproved);
+        }
+
+        let amount = Self::pay(&env, &mut mission, &mut submission);
+        env.storage().persistent().set(&key, &submission);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Mission(mission_id), &mission);
+
+        Ok(amount)
     }
 
-    pub fn pause_mission(env: Env, id: u64) -> Result<(), QuidError> {
-        let mut mission = Self::get_mission(env.clone(), id)?;
+    /// Tip an approved or paid submission with a bonus paid straight from the owner's wallet
+    pub fn award_bonus(
+        env: Env,
+        mission_id: u64,
+        hunter: Address,
+        amount: i128,
+    ) -> Result<(), QuidError> {
+        admin::require_not_paused(&env)?;
+        let mission = Self::get_mission(env.clone(), mission_id)?;
         mission.owner.require_auth();
-        mission.status = MissionStatus::Paused;
-        env.storage()
+
+        if amount <= 0 {
+            return Err(QuidError::InvalidAmount);
+        }
+
+        let key = DataKey::Submission(mission_id, hunter.clone());
+        let mut submission: Submission = env
+            .storage()
             .persistent()
-            .set(&DataKey::Mission(id), &mission);
+            .get(&key)
+            .ok_or(QuidError::SubmissionNotFound)?;
+
+        if !matches!(
+            submission.status,
+            SubmissionStatus::Approved | SubmissionStatus::Paid
+        ) {
+            return Err(QuidError::NotApproved);
+        }
+
+        token::Client::new(&env, &mission.reward_token).transfer(&mission.owner, &hunter, &amount);
+
+        submission.bonus += amount;
+        env.storage().persistent().set(&key, &submission);
+
+        reputation::record_earnings(&env, &hunter, &mission.reward_token, amount);
+        reputation::record_founder_payout(&env, &mission.owner, &mission.reward_token, amount);
+
+        PayoutBonusEvent {
+            mission_id,
+            hunter,
+            amount,
+        }
+        .publish(&env);
 
-        MissionPauseEvent { mission_id: id }.publish(&env);
         Ok(())
     }
 
-    pub fn update_mission_status(
+    /// Reject a pending submission, returning the hunter's stake and freeing its slot
+    pub fn reject_submission(
         env: Env,
         mission_id: u64,
-        new_status: MissionStatus,
+        hunter: Address,
+        reason_cid: String,
     ) -> Result<(), QuidError> {
         let mut mission = Self::get_mission(env.clone(), mission_id)?;
         mission.owner.require_auth();
-        mission.status = new_status;
+
+        if mission.status.is_closed() {
+            return Err(QuidError::MissionClosed);
+        }
+
+        let key = DataKey::Submission(mission_id, hunter.clone());
+        let mut submission = Self::get_pending_submission(&env, mission_id, &hunter)?;
+
+        Self::reject(&mut mission, &mut submission);
+        reputation::record_rejection(&env, &hunter);
+        submission.rejection_cid = Some(reason_cid.clone());
+        env.storage().persistent().set(&key, &submission);
         env.storage()
             .persistent()
             .set(&DataKey::Mission(mission_id), &mission);
-        Ok(())
-    }
 
-    pub fn get_mission_count(env: Env) -> u64 {
-        env.storage()
-            .instance()
-            .get(&DataKey::MissionCount)
-            .unwrap_or(0)
-    }
+        Self::return_stake(&env, mission_id, &hunter);
 
-    pub fn mission_exists(env: Env, mission_id: u64) -> bool {
-        env.storage()
-            .persistent()
-            .has(&DataKey::Mission(mission_id))
+        SubRejectEvent {
+            mission_id,
+            hunter,
+            reason_cid,
+        }
+        .publish(&env);
+
+        Ok(())
     }
 
-    fn validate_mission_params(_title: &String, reward_amount: i128) -> Result<(), QuidError> {
-        if reward_amount <= 0 {
+    /// Reject a submission as spam, forfeiting the hunter's stake to the mission owner
+    pub fn reject_spam(env: Env, mission_id: u64, hunter: Address) -> Result<(), QuidError> {
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        mission.owner.require_auth();
+
+        if mission.status.is_closed() {
+            return Err(QuidError::MissionClosed);
+        }
+
+        let key = DataKey::Submission(mission_id, hunter.clone());
+        let mut submission = Self::get_pending_submission(&env, mission_id, &hunter)?;
+
+        Self::reject(&mut mission, &mut submission);
+        reputation::record_rejection(&env, &hunter);
+        env.storage().persistent().set(&key, &submission);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Mission(mission_id), &mission);
+
+        let amount =
+            Self::release_stake(&env, mission_id, &hunter, &mission.owner).unwrap_or_default();
+
+        StakeSlashEvent {
+            mission_id,
+            hunter,
+            amount,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Withdraw the stake of an unpaid submission once its mission is closed. Approved
+    /// submissions only get their stake back; the earmarked reward stays claimable.
+    pub fn withdraw_stake(env: Env, mission_id: u64, hunter: Address) -> Result<i128, QuidError> {
+        hunter.require_auth();
+
+        let mission = Self::get_mission(env.clone(), mission_id)?;
+
+        if !mission.status.is_closed() {
+            return Err(QuidError::InvalidState);
+        }
+
+        let submission: Submission = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Submission(mission_id, hunter.clone()))
+            .ok_or(QuidError::SubmissionNotFound)?;
+
+        if !matches!(
+            submission.status,
+            SubmissionStatus::Pending | SubmissionStatus::Approved
+        ) {
+            return Err(QuidError::NotPending);
+        }
+
+        Self::return_stake(&env, mission_id, &hunter).ok_or(QuidError::StakeNotFound)
+    }
+
+    pub fn get_reputation(env: Env, hunter: Address) -> HunterProfile {
+        reputation::hunter_profile(&env, &hunter)
+    }
+
+    pub fn get_founder_stats(env: Env, owner: Address) -> FounderStats {
+        reputation::founder_stats(&env, &owner)
+    }
+
+    pub fn get_stake(env: Env, mission_id: u64, hunter: Address) -> Option<Stake> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::HunterStake(mission_id, hunter))
+    }
+
+    pub fn cancel_mission(env: Env, mission_id: u64) -> Result<(), QuidError> {
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        mission.owner.require_auth();
+
+        if mission.status.is_closed() {
+            return Err(QuidError::MissionClosed);
+        }
+
+        Self::refund_unused_escrow(&env, &mut mission)?;
+
+        Self::set_status(&env, &mut mission, MissionStatus::Cancelled)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Mission(mission_id), &mission);
+
+        MissionCancelEvent { mission_id }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Add slots to a live mission, pulling their escrow from the owner
+    pub fn increase_capacity(env: Env, mission_id: u64, extra_slots: u32) -> Result<(), QuidError> {
+        admin::require_not_paused(&env)?;
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        mission.owner.require_auth();
+
+        if mission.status.is_closed() {
+            return Err(QuidError::MissionClosed);
+        }
+        Self::require_bounty(&mission)?;
+        if extra_slots == 0 {
+            return Err(QuidError::InvalidAmount);
+        }
+
+        let extra_escrow: i128 = mission
+            .reward_amount
+            .checked_mul(extra_slots as i128)
+            .ok_or(QuidError::NegativeReward)?;
+        mission.max_participants = mission
+            .max_participants
+            .checked_add(extra_slots)
+            .ok_or(QuidError::InvalidAmount)?;
+
+        escrow::deposit(&env, &mission.reward_token, &mission.owner, extra_escrow);
+        mission.escrow_balance += extra_escrow;
+        Self::deposit_extra_rewards(&env, &mission.extra_rewards, &mission.owner, extra_slots)?;
+
+        Self::save_budget(&env, &mission);
+        Ok(())
+    }
+
+    /// Raise the reward for every slot not yet approved, pulling the difference from the owner.
+    /// Already approved submissions keep the amount they were approved for.
+    pub fn increase_reward(env: Env, mission_id: u64, new_amount: i128) -> Result<(), QuidError> {
+        admin::require_not_paused(&env)?;
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        mission.owner.require_auth();
+
+        if mission.status.is_closed() {
+            return Err(QuidError::MissionClosed);
+        }
+        Self::require_bounty(&mission)?;
+        if new_amount <= mission.reward_amount {
+            return Err(QuidError::InvalidAmount);
+        }
+
+        let remaining_slots = mission
+            .max_participants
+            .saturating_sub(mission.participants_count);
+        let extra_escrow: i128 = (new_amount - mission.reward_amount)
+            .checked_mul(remaining_slots as i128)
+            .ok_or(QuidError::NegativeReward)?;
+
+        if extra_escrow > 0 {
+            escrow::deposit(&env, &mission.reward_token, &mission.owner, extra_escrow);
+            mission.escrow_balance += extra_escrow;
+        }
+        mission.reward_amount = new_amount;
+
+        Self::save_budget(&env, &mission);
+        Ok(())
+    }
+
+    /// Remove unused slots from a live mission, refunding their escrow to the owner.
+    /// Slots that are paid, approved or reserved by a pending submission can't be removed.
+    pub fn decrease_capacity(env: Env, mission_id: u64, slots: u32) -> Result<(), QuidError> {
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        mission.owner.require_auth();
+
+        if mission.status.is_closed() {
+            return Err(QuidError::MissionClosed);
+        }
+        Self::require_bounty(&mission)?;
+        if slots == 0 {
+            return Err(QuidError::InvalidAmount);
+        }
+
+        let slots_in_use = match mission.slot_policy {
+            SlotPolicy::FirstCome => mission.submissions_count,
+            SlotPolicy::Competition => mission.participants_count,
+        };
+        let unused_slots = mission.max_participants.saturating_sub(slots_in_use);
+        if slots > unused_slots {
+            return Err(QuidError::InvalidState);
+        }
+
+        let refund_amount: i128 = mission
+            .reward_amount
+            .checked_mul(slots as i128)
+            .ok_or(QuidError::NegativeReward)?;
+        mission.max_participants -= slots;
+
+        escrow::withdraw(&env, &mission.reward_token, &mission.owner, refund_amount);
+        mission.escrow_balance -= refund_amount;
+        Self::refund_extra_rewards(&env, &mission, slots)?;
+
+        Self::complete_if_full(&env, &mut mission)?;
+        Self::save_budget(&env, &mission);
+        Ok(())
+    }
+
+    /// Rank a contest's pending submissions, best first, and pay them the prize table in
+    /// that order. Prizes left without a winner go back to the owner and the mission completes.
+    pub fn finalize_rankings(
+        env: Env,
+        mission_id: u64,
+        ordered_hunters: Vec<Address>,
+    ) -> Result<(), QuidError> {
+        admin::require_not_paused(&env)?;
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        mission.owner.require_auth();
+
+        if mission.status.is_closed() {
+            return Err(QuidError::MissionClosed);
+        }
+        let prizes = match &mission.kind {
+            MissionKind::Contest(prizes) => prizes.clone(),
+            MissionKind::Bounty => return Err(QuidError::WrongMissionKind),
+        };
+        if ordered_hunters.len() > prizes.len() {
+            return Err(QuidError::InvalidPrizeTable);
+        }
+
+        for (hunter, prize) in ordered_hunters.iter().zip(prizes.iter()) {
+            let key = DataKey::Submission(mission_id, hunter.clone());
+            let mut submission = Self::get_pending_submission(&env, mission_id, &hunter)?;
+
+            Self::approve(&env, &mut mission, &mut submission, prize);
+            Self::pay(&env, &mut mission, &mut submission);
+            env.storage().persistent().set(&key, &submission);
+        }
+
+        Self::set_status(&env, &mut mission, MissionStatus::Completed)?;
+        Self::refund_unused_escrow(&env, &mut mission)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Mission(mission_id), &mission);
+
+        Ok(())
+    }
+
+    /// Expire a mission whose review deadline has passed. Anyone can call this: pending
+    /// submissions are settled per the mission's expiry policy, unused escrow goes back
+    /// to the owner and approved rewards stay claimable.
+    ///
+    /// Each call settles the next `limit` submissions (capped at the index page size).
+    /// Returns `true` once the last page is done and the mission is expired; call again
+    /// while it returns `false`. While the contract is paused only `RefundPending` missions
+    /// can expire, since `PayPending` ones would pay out.
+    pub fn expire_mission(env: Env, mission_id: u64, limit: u32) -> Result<bool, QuidError> {
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+
+        if mission.expiry_policy == ExpiryPolicy::PayPending {
+            admin::require_not_paused(&env)?;
+        }
+
+        if mission.status.is_closed() {
+            return Err(QuidError::MissionClosed);
+        }
+        match mission.review_deadline {
+            Some(deadline) if env.ledger().timestamp() > deadline => {}
+            _ => return Err(QuidError::DeadlineNotReached),
+        }
+
+        let list = IndexKey::MissionSubmissions(mission_id);
+        let cursor_key = DataKey::ExpiryCursor(mission_id);
+        let cursor: u32 = env.storage().persistent().get(&cursor_key).unwrap_or(0);
+        let hunters: Vec<Address> = index::page(&env, &list, cursor, limit);
+
+        for hunter in hunters.iter() {
+            let key = DataKey::Submission(mission_id, hunter.clone());
+            let mut submission = match Self::get_pending_submission(&env, mission_id, &hunter) {
+                Ok(submission) => submission,
+                Err(_) => continue,
+            };
+
+            if mission.expiry_policy == ExpiryPolicy::PayPending
+                && mission.kind == MissionKind::Bounty
+                && mission.participants_count < mission.max_participants
+            {
+                let amount = mission.reward_amount;
+                Self::approve(&env, &mut mission, &mut submission, amount);
+                Self::pay(&env, &mut mission, &mut submission);
+            } else {
+                Self::reject(&mut mission, &mut submission);
+                Self::return_stake(&env, mission_id, &hunter);
+            }
+            env.storage().persistent().set(&key, &submission);
+        }
+
+        if let Some(next) = index::next_cursor(&env, &list, cursor, hunters.len()) {
+            env.storage().persistent().set(&cursor_key, &next);
+            env.storage()
+                .persistent()
+                .extend_ttl(&cursor_key, 5184000, 5184000);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Mission(mission_id), &mission);
+            return Ok(false);
+        }
+        env.storage().persistent().remove(&cursor_key);
+
+        Self::refund_unused_escrow(&env, &mut mission)?;
+
+        Self::set_status(&env, &mut mission, MissionStatus::Expired)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Mission(mission_id), &mission);
+
+        MissionExpireEvent { mission_id }.publish(&env);
+
+        Ok(true)
+    }
+
+    /// Pause a live mission. While paused no new submissions or edits are accepted,
+    /// but the owner can still review, approve, pay and reject existing submissions.
+    pub fn pause_mission(env: Env, id: u64) -> Result<(), QuidError> {
+        let mut mission = Self::get_mission(env.clone(), id)?;
+        mission.owner.require_auth();
+        Self::set_status(&env, &mut mission, MissionStatus::Paused)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Mission(id), &mission);
+
+        MissionPauseEvent { mission_id: id }.publish(&env);
+        Ok(())
+    }
+
+    /// Resume a paused mission, restoring the status it held before the pause
+    pub fn resume_mission(env: Env, mission_id: u64) -> Result<(), QuidError> {
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        mission.owner.require_auth();
+
+        if mission.status != MissionStatus::Paused {
+            return Err(QuidError::InvalidState);
+        }
+
+        let previous = mission.paused_from;
+        Self::set_status(&env, &mut mission, previous)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Mission(mission_id), &mission);
+
+        MissionResumeEvent { mission_id }.publish(&env);
+        Ok(())
+    }
+
+    /// Move a mission along its lifecycle. Completing and cancelling settle escrow,
+    /// so those statuses are only reachable through payouts and `cancel_mission`;
+    /// pausing goes through `pause_mission` and `resume_mission`.
+    pub fn update_mission_status(
+        env: Env,
+        mission_id: u64,
+        new_status: MissionStatus,
+    ) -> Result<(), QuidError> {
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        mission.owner.require_auth();
+
+        if new_status.is_closed()
+            || new_status == MissionStatus::Paused
+            || mission.status == MissionStatus::Paused
+        {
+            return Err(QuidError::InvalidState);
+        }
+
+        Self::set_status(&env, &mut mission, new_status)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Mission(mission_id), &mission);
+        Ok(())
+    }
+
+    /// Compare what the contract owes in `token` with what it actually holds
+    pub fn check_solvency(env: Env, token: Address) -> SolvencyReport {
+        let liabilities = escrow::liabilities(&env, &token);
+        let balance = token::Client::new(&env, &token).balance(&env.current_contract_address());
+        SolvencyReport {
+            liabilities,
+            balance,
+            solvent: balance >= liabilities,
+        }
+    }
+
+    pub fn get_mission_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MissionCount)
+            .unwrap_or(0)
+    }
+
+    pub fn mission_exists(env: Env, mission_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Mission(mission_id))
+    }
+
+    /// Appends a mission to the index of `status` and remembers where, so it can be removed.
+    fn index_status(env: &Env, mission_id: u64, status: MissionStatus) {
+        let pos = index::push(env, &IndexKey::Status(status), mission_id);
+        let key = DataKey::StatusPos(mission_id);
+        env.storage().persistent().set(&key, &pos);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, 5184000, 5184000);
+    }
+
+    /// Every mission status change goes through here so the lifecycle is enforced in one place.
+    fn set_status(
+        env: &Env,
+        mission: &mut Mission,
+        new_status: MissionStatus,
+    ) -> Result<(), QuidError> {
+        let from = mission.status;
+        if !from.can_transition_to(new_status) {
+            return Err(QuidError::InvalidState);
+        }
+        mission.status = new_status;
+        if let Some(pos) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::StatusPos(mission.id))
+        {
+            index::remove_id(env, &IndexKey::Status(from), pos);
+        }
+        Self::index_status(env, mission.id, new_status);
+        if new_status == MissionStatus::Paused {
+            mission.paused_from = from;
+        }
+        if new_status.is_closed() {
+            reputation::record_mission_closed(env, &mission.owner, new_status);
+        }
+
+        MissionStatusEvent {
+            mission_id: mission.id,
+            from,
+            to: new_status,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// List missions in id order, starting after `start_after` (0 for the first page)
+    pub fn list_missions(env: Env, start_after: u64, limit: u32) -> Vec<Mission> {
+        let count = Self::get_mission_count(env.clone());
+        let mut missions = Vec::new(&env);
+        let mut id = start_after;
+        while id < count && missions.len() < limit.min(index::MAX_PAGE_SIZE) {
+            id += 1;
+            if let Ok(mission) = Self::get_mission(env.clone(), id) {
+                missions.push_back(mission);
+            }
+        }
+        missions
+    }
+
+    /// List missions currently in `status`, in the order they entered it. Each call reads up
+    /// to `limit` index positions from `cursor` (0 for the first page); missions that have
+    /// since left the status are skipped, so a page can be short or empty. Continue from
+    /// `next_cursor` until it is `None`.
+    pub fn list_missions_by_status(
+        env: Env,
+        status: MissionStatus,
+        cursor: u32,
+        limit: u32,
+    ) -> MissionPage {
+        let list = IndexKey::Status(status);
+        let ids: Vec<u64> = index::page(&env, &list, cursor, limit);
+
+        MissionPage {
+            missions: Self::load_missions(&env, &index::live_ids(&env, &ids)),
+            next_cursor: index::next_cursor(&env, &list, cursor, ids.len()),
+        }
+    }
+
+    pub fn get_submission(
+        env: Env,
+        mission_id: u64,
+        hunter: Address,
+    ) -> Result<Submission, QuidError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Submission(mission_id, hunter))
+            .ok_or(QuidError::SubmissionNotFound)
+    }
+
+    /// List a mission's submissions in submission order, optionally only those in one status.
+    /// Each call reads up to `limit` submissions from position `cursor` (0 for the first page)
+    /// and keeps the ones matching the filter, so a filtered page can be short or empty.
+    /// Continue from `next_cursor` until it is `None`; positions never shift between calls.
+    pub fn list_submissions(
+        env: Env,
+        mission_id: u64,
+        status_filter: Option<SubmissionStatus>,
+        cursor: u32,
+        limit: u32,
+    ) -> SubmissionPage {
+        let list = IndexKey::MissionSubmissions(mission_id);
+        let hunters: Vec<Address> = index::page(&env, &list, cursor, limit);
+
+        let mut submissions = Vec::new(&env);
+        for hunter in hunters.iter() {
+            if let Ok(submission) = Self::get_submission(env.clone(), mission_id, hunter) {
+                if status_filter.is_none_or(|status| submission.status == status) {
+                    submissions.push_back(submission);
+                }
+            }
+        }
+
+        SubmissionPage {
+            submissions,
+            next_cursor: index::next_cursor(&env, &list, cursor, hunters.len()),
+        }
+    }
+
+    /// List the missions created by `owner` in creation order. `cursor` is the number of
+    /// missions already read.
+    pub fn missions_by_owner(env: Env, owner: Address, cursor: u32, limit: u32) -> Vec<Mission> {
+        let ids = index::page(&env, &IndexKey::OwnerMissions(owner), cursor, limit);
+        Self::load_missions(&env, &ids)
+    }
+
+    /// List the missions `hunter` submitted to with the current status of each submission,
+    /// in submission order. `cursor` is the number of entries already read.
+    pub fn submissions_by_hunter(
+        env: Env,
+        hunter: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<(u64, SubmissionStatus)> {
+        let ids: Vec<u64> = index::page(
+            &env,
+            &IndexKey::HunterSubmissions(hunter.clone()),
+            cursor,
+            limit,
+        );
+
+        let mut entries = Vec::new(&env);
+        for mission_id in ids.iter() {
+            if let Ok(submission) = Self::get_submission(env.clone(), mission_id, hunter.clone()) {
+                entries.push_back((mission_id, submission.status));
+            }
+        }
+        entries
+    }
+
+    fn load_missions(env: &Env, ids: &Vec<u64>) -> Vec<Mission> {
+        let mut missions = Vec::new(env);
+        for id in ids.iter() {
+            if let Ok(mission) = Self::get_mission(env.clone(), id) {
+                missions.push_back(mission);
+            }
+        }
+        missions
+    }
+
+    fn validate_mission_params(_title: &String, reward_amount: i128) -> Result<(), QuidError> {
+        if reward_amount <= 0 {
             return Err(QuidError::NegativeReward);
         }
         Ok(())
     }
 
+    fn get_pending_submission(
+        env: &Env,
+        mission_id: u64,
+        hunter: &Address,
+    ) -> Result<Submission, QuidError> {
+        let submission: Submission = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Submission(mission_id, hunter.clone()))
+            .ok_or(QuidError::SubmissionNotFound)?;
+
+        if submission.status == SubmissionStatus::Paid {
+            return Err(QuidError::AlreadyPaid);
+        }
+        if submission.status != SubmissionStatus::Pending {
+            return Err(QuidError::NotPending);
+        }
+        Ok(submission)
+    }
+
+    /// Marks a pending submission as approved and reserves a slot's reward for it.
+    fn approve(env: &Env, mission: &mut Mission, submission: &mut Submission, amount: i128) {
+        submission.status = SubmissionStatus::Approved;
+        submission.reward_amount = amount;
+        mission.earmarked += amount;
+        mission.participants_count += 1;
+        reputation::record_review_time(env, &mission.owner, submission.submitted_at);
+    }
+
+    /// Collects every extra reward for `slots` more slots from `from`.
+    fn deposit_extra_rewards(
+        env: &Env,
+        extra_rewards: &Vec<RewardComponent>,
+        from: &Address,
+        slots: u32,
+    ) -> Result<(), QuidError> {
+        for extra in extra_rewards.iter() {
+            let amount = extra
+                .amount
+                .checked_mul(slots as i128)
+                .ok_or(QuidError::NegativeReward)?;
+            escrow::deposit(env, &extra.token, from, amount);
+        }
+        Ok(())
+    }
+
+    /// Returns the extra rewards held for `slots` unallocated slots to the owner.
+    fn refund_extra_rewards(env: &Env, mission: &Mission, slots: u32) -> Result<(), QuidError> {
+        if slots == 0 {
+            return Ok(());
+        }
+        for extra in mission.extra_rewards.iter() {
+            let amount = extra
+                .amount
+                .checked_mul(slots as i128)
+                .ok_or(QuidError::NegativeReward)?;
+            escrow::withdraw(env, &extra.token, &mission.owner, amount);
+        }
+        Ok(())
+    }
+
+    fn require_bounty(mission: &Mission) -> Result<(), QuidError> {
+        match mission.kind {
+            MissionKind::Bounty => Ok(()),
+            MissionKind::Contest(_) => Err(QuidError::WrongMissionKind),
+        }
+    }
+
+    /// Checks a contest's prizes are positive, best first and match the mission's reward
+    /// and slots, returning the escrow they need.
+    fn validate_prize_table(
+        prizes: &Vec<i128>,
+        reward_amount: i128,
+        max_participants: u32,
+    ) -> Result<i128, QuidError> {
+        if prizes.is_empty()
+            || prizes.len() != max_participants
+            || prizes.get(0) != Some(reward_amount)
+        {
+            return Err(QuidError::InvalidPrizeTable);
+        }
+
+        let mut total: i128 = 0;
+        let mut previous = reward_amount;
+        for prize in prizes.iter() {
+            if prize <= 0 || prize > previous {
+                return Err(QuidError::InvalidPrizeTable);
+            }
+            previous = prize;
+            total = total.checked_add(prize).ok_or(QuidError::NegativeReward)?;
+        }
+        Ok(total)
+    }
+
+    /// Approves a hunter's pending submission and completes the mission if it was the last slot.
+    fn approve_pending(
+        env: &Env,
+        mission: &mut Mission,
+        hunter: &Address,
+        amount: i128,
+    ) -> Result<(), QuidError> {
+        let key = DataKey::Submission(mission.id, hunter.clone());
+        let mut submission = Self::get_pending_submission(env, mission.id, hunter)?;
+
+        Self::approve(env, mission, &mut submission, amount);
+        Self::complete_if_full(env, mission)?;
+        env.storage().persistent().set(&key, &submission);
+
+        SubApproveEvent {
+            mission_id: mission.id,
+            hunter: hunter.clone(),
+            amount,
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    /// Validates a custom reward against the mission's per-submission cap.
+    fn resolve_reward(mission: &Mission, amount: Option<i128>) -> Result<i128, QuidError> {
+        match amount {
+            None => Ok(mission.reward_amount),
+            Some(amount) if amount > 0 && amount <= mission.reward_amount => Ok(amount),
+            Some(_) => Err(QuidError::InvalidAmount),
+        }
+    }
+
+    fn save_budget(env: &Env, mission: &Mission) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Mission(mission.id), mission);
+
+        MissionBudgetEvent {
+            mission_id: mission.id,
+            reward_amount: mission.reward_amount,
+            max_participants: mission.max_participants,
+        }
+        .publish(env);
+    }
+
+    /// Approves and pays a pending submission in one step. The caller saves the mission.
+    fn payout(
+        env: &Env,
+        mission: &mut Mission,
+        hunter: &Address,
+        amount: i128,
+    ) -> Result<(), QuidError> {
+        let key = DataKey::Submission(mission.id, hunter.clone());
+        let mut submission = Self::get_pending_submission(env, mission.id, hunter)?;
+
+        Self::approve(env, mission, &mut submission, amount);
+        Self::complete_if_full(env, mission)?;
+        Self::pay(env, mission, &mut submission);
+
+        env.storage().persistent().set(&key, &submission);
+        Ok(())
+    }
+
+    /// Completes a mission once every slot is approved, refunding whatever the owner
+    /// saved by awarding less than the full reward.
+    fn complete_if_full(env: &Env, mission: &mut Mission) -> Result<(), QuidError> {
+        if mission.max_participants > 0 && mission.participants_count >= mission.max_participants {
+            Self::set_status(env, mission, MissionStatus::Completed)?;
+            Self::refund_unused_escrow(env, mission)?;
+        }
+        Ok(())
+    }
+
+    /// Sends every part of the mission's escrow that isn't earmarked for a hunter back to the owner.
+    fn refund_unused_escrow(env: &Env, mission: &mut Mission) -> Result<i128, QuidError> {
+        let refund_amount = mission.escrow_balance - mission.earmarked;
+
+        if refund_amount > 0 {
+            escrow::withdraw(env, &mission.reward_token, &mission.owner, refund_amount);
+            mission.escrow_balance -= refund_amount;
+        }
+
+        let unallocated_slots = mission
+            .max_participants
+            .saturating_sub(mission.participants_count);
+        Self::refund_extra_rewards(env, mission, unallocated_slots)?;
+        Ok(refund_amount)
+    }
+
+    /// Marks a pending submission as rejected and releases the slot it was holding.
+    fn reject(mission: &mut Mission, submission: &mut Submission) {
+        submission.status = SubmissionStatus::Rejected;
+        mission.submissions_count = mission.submissions_count.saturating_sub(1);
+    }
+
+    /// Pays an approved submission and returns the hunter's stake, sending the mission's
+    /// fee share of the primary reward to the treasury. Extra rewards are paid without a
+    /// fee. Returns what the hunter received in the reward token.
+    fn pay(env: &Env, mission: &mut Mission, submission: &mut Submission) -> i128 {
+        let hunter = submission.hunter.clone();
+        let fee = submission.reward_amount * mission.fee_bps as i128 / BPS_DENOMINATOR as i128;
+        let amount = submission.reward_amount - fee;
+
+        escrow::withdraw(env, &mission.reward_token, &hunter, amount);
+        if fee > 0 {
+            escrow::withdraw(
+                env,
+                &mission.reward_token,
+                &admin::config(env).treasury,
+                fee,
+            );
+        }
+        mission.escrow_balance -= submission.reward_amount;
+        mission.earmarked -= submission.reward_amount;
+        submission.status = SubmissionStatus::Paid;
+        reputation::record_payout(env, &hunter, &mission.reward_token, amount);
+        reputation::record_founder_payout(
+            env,
+            &mission.owner,
+            &mission.reward_token,
+            submission.reward_amount,
+        );
+        for extra in mission.extra_rewards.iter() {
+            escrow::withdraw(env, &extra.token, &hunter, extra.amount);
+            reputation::record_earnings(env, &hunter, &extra.token, extra.amount);
+            reputation::record_founder_payout(env, &mission.owner, &extra.token, extra.amount);
+        }
+
+        Self::return_stake(env, mission.id, &hunter);
+
+        PayoutDoneEvent {
+            mission_id: mission.id,
+            hunter,
+            amount,
+            fee,
+        }
+        .publish(env);
+
+        amount
+    }
+
+    fn return_stake(env: &Env, mission_id: u64, hunter: &Address) -> Option<i128> {
+        let amount = Self::release_stake(env, mission_id, hunter, hunter)?;
+        StakeReturnEvent {
+            mission_id,
+            hunter: hunter.clone(),
+            amount,
+        }
+        .publish(env);
+        Some(amount)
+    }
+
+    /// Removes a hunter's stake record and sends the staked tokens to `to`.
+    fn release_stake(env: &Env, mission_id: u64, hunter: &Address, to: &Address) -> Option<i128> {
+        let stake_key = DataKey::HunterStake(mission_id, hunter.clone());
+        let stake: Stake = env.storage().persistent().get(&stake_key)?;
+        env.storage().persistent().remove(&stake_key);
+
+        escrow::withdraw(env, &stake.token, to, stake.amount);
+
+        Some(stake.amount)
+    }
+
+    fn validate_deadlines(env: &Env, options: &MissionOptions) -> Result<(), QuidError> {
+        let now = env.ledger().timestamp();
+        if let Some(deadline) = options.submission_deadline {
+            if deadline <= now {
+                return Err(QuidError::InvalidDeadline);
+            }
+        }
+        if let Some(deadline) = options.review_deadline {
+            if deadline <= now || options.submission_deadline.is_some_and(|s| deadline < s) {
+                return Err(QuidError::InvalidDeadline);
+            }
+        }
+        Ok(())
+    }
+
     fn get_next_mission_id(env: &Env) -> u64 {
         let mut count: u64 = env
             .storage()
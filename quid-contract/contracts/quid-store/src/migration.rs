@@ -0,0 +1,74 @@
+use soroban_sdk::{Address, Env, IntoVal, Val, Vec};
+
+use crate::index;
+use crate::types::{DataKey, IndexKey, MigrationProgress, Mission, Submission};
+
+/// Layout version of the records this build reads and writes.
+pub const SCHEMA_VERSION: u32 = 1;
+
+pub fn schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(0)
+}
+
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SchemaVersion, &version);
+}
+
+/// Where a migration still in progress resumes, or the first mission if none is.
+pub fn cursor(env: &Env) -> MigrationProgress {
+    env.storage()
+        .instance()
+        .get(&DataKey::MigrationCursor)
+        .unwrap_or(MigrationProgress {
+            mission_id: 1,
+            submission_pos: 0,
+        })
+}
+
+pub fn set_cursor(env: &Env, progress: &MigrationProgress) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MigrationCursor, progress);
+}
+
+pub fn clear_cursor(env: &Env) {
+    env.storage().instance().remove(&DataKey::MigrationCursor);
+}
+
+/// Rewrites a mission's submissions from position `from` onwards and then the mission
+/// itself in the current layout, handling at most `budget` records. Returns the number
+/// handled and where to resume within the mission, or `None` once it is done. When a
+/// record type changes, decode the previous layout here and convert it before saving.
+pub fn migrate_mission(env: &Env, mission_id: u64, from: u32, budget: u32) -> (u32, Option<u32>) {
+    let key = DataKey::Mission(mission_id);
+    let Some(mission) = env.storage().persistent().get::<_, Mission>(&key) else {
+        return (0, None);
+    };
+
+    let list = IndexKey::MissionSubmissions(mission_id);
+    let hunters: Vec<Address> = index::page(env, &list, from, budget);
+    for hunter in hunters.iter() {
+        let key = DataKey::Submission(mission_id, hunter);
+        if let Some(submission) = env.storage().persistent().get::<_, Submission>(&key) {
+            rewrite(env, &key, &submission);
+        }
+    }
+    let handled = hunters.len();
+    let pos = from + handled;
+    if pos < index::len(env, &list) || handled >= budget {
+        return (handled, Some(pos));
+    }
+
+    rewrite(env, &key, &mission);
+    (handled + 1, None)
+}
+
+fn rewrite<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage().persistent().extend_ttl(key, 5184000, 5184000);
+}
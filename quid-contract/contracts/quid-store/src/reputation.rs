@@ -0,0 +1,146 @@
+use soroban_sdk::{Address, Env, Map, Vec};
+
+use crate::types::{DataKey, FounderStats, HunterProfile, MissionStatus};
+
+/// How many of a founder's most recent review times feed `median_review_time`.
+pub const REVIEW_TIME_WINDOW: u32 = 64;
+
+/// Returns the hunter's profile, or an empty one if they have no history yet.
+pub fn hunter_profile(env: &Env, hunter: &Address) -> HunterProfile {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HunterProfile(hunter.clone()))
+        .unwrap_or_else(|| HunterProfile {
+            paid_count: 0,
+            rejected_count: 0,
+            total_earned: Map::new(env),
+            first_activity: 0,
+            last_activity: 0,
+        })
+}
+
+/// Counts a paid submission and adds `amount` of `token` to the hunter's earnings.
+pub fn record_payout(env: &Env, hunter: &Address, token: &Address, amount: i128) {
+    let mut profile = hunter_profile(env, hunter);
+    profile.paid_count += 1;
+    let earned = profile.total_earned.get(token.clone()).unwrap_or(0);
+    profile.total_earned.set(token.clone(), earned + amount);
+    save_hunter_profile(env, hunter, profile);
+}
+
+/// Adds to the hunter's earnings without counting another paid submission.
+pub fn record_earnings(env: &Env, hunter: &Address, token: &Address, amount: i128) {
+    let mut profile = hunter_profile(env, hunter);
+    let earned = profile.total_earned.get(token.clone()).unwrap_or(0);
+    profile.total_earned.set(token.clone(), earned + amount);
+    save_hunter_profile(env, hunter, profile);
+}
+
+/// Counts a submission the mission owner rejected.
+pub fn record_rejection(env: &Env, hunter: &Address) {
+    let mut profile = hunter_profile(env, hunter);
+    profile.rejected_count += 1;
+    save_hunter_profile(env, hunter, profile);
+}
+
+fn save_hunter_profile(env: &Env, hunter: &Address, mut profile: HunterProfile) {
+    let now = env.ledger().timestamp();
+    if profile.first_activity == 0 {
+        profile.first_activity = now;
+    }
+    profile.last_activity = now;
+
+    let key = DataKey::HunterProfile(hunter.clone());
+    env.storage().persistent().set(&key, &profile);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, 5184000, 5184000);
+}
+
+/// Returns the founder's stats, or empty ones if they have never created a mission.
+pub fn founder_stats(env: &Env, owner: &Address) -> FounderStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FounderStats(owner.clone()))
+        .unwrap_or_else(|| FounderStats {
+            missions_created: 0,
+            missions_completed: 0,
+            missions_cancelled: 0,
+            missions_expired: 0,
+            total_paid: Map::new(env),
+            median_review_time: 0,
+        })
+}
+
+pub fn record_mission_created(env: &Env, owner: &Address) {
+    let mut stats = founder_stats(env, owner);
+    stats.missions_created += 1;
+    save_founder_stats(env, owner, &stats);
+}
+
+/// Counts a mission reaching a terminal status.
+pub fn record_mission_closed(env: &Env, owner: &Address, status: MissionStatus) {
+    let mut stats = founder_stats(env, owner);
+    match status {
+        MissionStatus::Completed => stats.missions_completed += 1,
+        MissionStatus::Cancelled => stats.missions_cancelled += 1,
+        MissionStatus::Expired => stats.missions_expired += 1,
+        _ => return,
+    }
+    save_founder_stats(env, owner, &stats);
+}
+
+/// Adds `amount` of `token` to the rewards the founder has paid out.
+pub fn record_founder_payout(env: &Env, owner: &Address, token: &Address, amount: i128) {
+    let mut stats = founder_stats(env, owner);
+    let paid = stats.total_paid.get(token.clone()).unwrap_or(0);
+    stats.total_paid.set(token.clone(), paid + amount);
+    save_founder_stats(env, owner, &stats);
+}
+
+/// Records how long a submission waited for the founder's decision and refreshes the median.
+pub fn record_review_time(env: &Env, owner: &Address, submitted_at: u64) {
+    let key = DataKey::FounderReviewTimes(owner.clone());
+    let mut recent: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+
+    if recent.len() >= REVIEW_TIME_WINDOW {
+        recent.pop_front();
+    }
+    recent.push_back(env.ledger().timestamp().saturating_sub(submitted_at));
+    env.storage().persistent().set(&key, &recent);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, 5184000, 5184000);
+
+    let mut sorted: Vec<u64> = Vec::new(env);
+    for time in recent.iter() {
+        let pos = match sorted.binary_search(time) {
+            Ok(pos) | Err(pos) => pos,
+        };
+        sorted.insert(pos, time);
+    }
+
+    let len = sorted.len();
+    let mid = sorted.get_unchecked(len / 2);
+    let median = if len.is_multiple_of(2) {
+        (sorted.get_unchecked(len / 2 - 1) + mid) / 2
+    } else {
+        mid
+    };
+
+    let mut stats = founder_stats(env, owner);
+    stats.median_review_time = median;
+    save_founder_stats(env, owner, &stats);
+}
+
+fn save_founder_stats(env: &Env, owner: &Address, stats: &FounderStats) {
+    let key = DataKey::FounderStats(owner.clone());
+    env.storage().persistent().set(&key, stats);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, 5184000, 5184000);
+}
@@ -1,25 +1,46 @@
 #![cfg(test)]
 
 use super::*;
-use crate::types::MissionStatus;
+use crate::types::{
+    Config, ExpiryPolicy, GatingRule, MigrationProgress, MissionKind, MissionOptions,
+    MissionStatus, PayoutOutcome, RewardComponent, SlotPolicy, SubmissionStatus,
+};
+use soroban_sdk::testutils::{Address as _, Ledger};
 use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{vec, Address, BytesN, Env, String, Vec};
 
 fn setup_test_env() -> (Env, Address, Address, Address) {
     let env = Env::default();
     env.mock_all_auths();
 
-    let contract_id = env.register(QuidStoreContract, ());
+    let admin = Address::generate(&env);
+    let contract_id = env.register(QuidStoreContract, (admin,));
     let token_admin = Address::generate(&env);
     let token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
     let token_address = token_contract.address();
     let owner = Address::generate(&env);
     let token_admin_client = StellarAssetClient::new(&env, &token_address);
     token_admin_client.mint(&owner, &1_000_000_000_000);
+    QuidStoreContractClient::new(&env, &contract_id).add_allowed_token(&token_address);
 
     (env, contract_id, owner, token_address)
 }
 
+fn default_options(env: &Env) -> MissionOptions {
+    MissionOptions {
+        slot_policy: SlotPolicy::FirstCome,
+        submission_deadline: None,
+        review_deadline: None,
+        expiry_policy: ExpiryPolicy::PayPending,
+        gating: Vec::new(env),
+        min_reputation: 0,
+        stake_token: None,
+        min_stake: 1,
+        kind: MissionKind::Bounty,
+        extra_rewards: Vec::new(env),
+    }
+}
+
 fn mint_tokens_for_hunter(env: &Env, token_address: &Address, hunter: &Address, amount: i128) {
     let token_admin_client = StellarAssetClient::new(env, token_address);
     token_admin_client.mint(hunter, &amount);
@@ -44,16 +65,17 @@ fn test_happy_path_create_submit_payout() {
         &token_address,
         &reward,
         &5,
+        &default_options(&env),
     );
 
     let cid = String::from_str(&env, "QmSubmission");
     client.submit_feedback(&mission_id, &hunter, &cid, &token_address, &stake);
 
     let balance_before = token_client.balance(&hunter);
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &hunter, &None);
 
     let balance_after = token_client.balance(&hunter);
-    assert_eq!(balance_after, balance_before + reward);
+    assert_eq!(balance_after, balance_before + reward + stake);
 
     let mission = client.get_mission(&mission_id);
     assert_eq!(mission.participants_count, 1);
@@ -75,6 +97,7 @@ fn test_prevent_double_submission() {
         &token_address,
         &100,
         &5,
+        &default_options(&env),
     );
 
     let cid = String::from_str(&env, "QmFirst");
@@ -99,6 +122,7 @@ fn test_cancel_mission_refund() {
         &token_address,
         &reward,
         &slots,
+        &default_options(&env),
     );
 
     let contract_balance = token_client.balance(&contract_id);
@@ -114,7 +138,7 @@ fn test_cancel_mission_refund() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #2)")]
+#[should_panic(expected = "Error(Contract, #3)")]
 fn test_mission_capacity_limit() {
     let (env, contract_id, owner, token_address) = setup_test_env();
     let client = QuidStoreContractClient::new(&env, &contract_id);
@@ -126,6 +150,7 @@ fn test_mission_capacity_limit() {
         &token_address,
         &100,
         &1,
+        &default_options(&env),
     );
 
     let hunter1 = Address::generate(&env);
@@ -135,10 +160,8 @@ fn test_mission_capacity_limit() {
     let cid = String::from_str(&env, "QmVal");
 
     client.submit_feedback(&mission_id, &hunter1, &cid, &token_address, &10);
+    // The only slot is already reserved by hunter1's pending submission
     client.submit_feedback(&mission_id, &hunter2, &cid, &token_address, &10);
-
-    client.payout_participant(&mission_id, &hunter1);
-    client.payout_participant(&mission_id, &hunter2);
 }
 
 #[test]
@@ -157,6 +180,7 @@ fn test_cannot_payout_twice() {
         &token_address,
         &100,
         &5,
+        &default_options(&env),
     );
 
     client.submit_feedback(
@@ -166,8 +190,8 @@ fn test_cannot_payout_twice() {
         &token_address,
         &10,
     );
-    client.payout_participant(&mission_id, &hunter);
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &hunter, &None);
+    client.payout_participant(&mission_id, &hunter, &None);
 }
 
 #[test]
@@ -206,6 +230,7 @@ fn test_create_mission_negative_reward() {
         &token_address,
         &0,
         &5,
+        &default_options(&env),
     );
 }
 
@@ -225,6 +250,7 @@ fn test_submit_feedback_when_paused() {
         &token_address,
         &100,
         &5,
+        &default_options(&env),
     );
     client.pause_mission(&mission_id);
     client.submit_feedback(
@@ -252,6 +278,7 @@ fn test_cancel_mission_partial_payouts_refund() {
         &token_address,
         &reward,
         &slots,
+        &default_options(&env),
     );
 
     let hunter = Address::generate(&env);
@@ -263,7 +290,7 @@ fn test_cancel_mission_partial_payouts_refund() {
         &token_address,
         &10,
     );
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &hunter, &None);
 
     let owner_balance_before_cancel = token_client.balance(&owner);
     client.cancel_mission(&mission_id);
@@ -276,7 +303,7 @@ fn test_cancel_mission_partial_payouts_refund() {
     );
 
     let contract_balance = token_client.balance(&contract_id);
-    assert_eq!(contract_balance, 10);
+    assert_eq!(contract_balance, 0);
 
     let mission = client.get_mission(&mission_id);
     assert_eq!(mission.status, MissionStatus::Cancelled);
@@ -296,8 +323,9 @@ fn test_payout_without_submission() {
         &token_address,
         &100,
         &5,
+        &default_options(&env),
     );
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &hunter, &None);
 }
 
 #[test]
@@ -317,6 +345,7 @@ fn test_stake_deducted_on_submission() {
         &token_address,
         &100,
         &5,
+        &default_options(&env),
     );
 
     let hunter_balance_before = token_client.balance(&hunter);
@@ -356,6 +385,7 @@ fn test_stake_invalid_amount_zero() {
         &token_address,
         &100,
         &5,
+        &default_options(&env),
     );
 
     client.submit_feedback(
@@ -383,6 +413,7 @@ fn test_stake_invalid_amount_negative() {
         &token_address,
         &100,
         &5,
+        &default_options(&env),
     );
 
     client.submit_feedback(
@@ -409,6 +440,7 @@ fn test_update_submission_success() {
         &token_address,
         &100,
         &5,
+        &default_options(&env),
     );
 
     let original_cid = String::from_str(&env, "QmOriginal");
@@ -418,7 +450,7 @@ fn test_update_submission_success() {
     client.update_submission(&mission_id, &hunter, &new_cid);
 
     // Verify submission was updated (by trying to payout - should work)
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &hunter, &None);
 }
 
 #[test]
@@ -437,6 +469,7 @@ fn test_update_submission_after_payout() {
         &token_address,
         &100,
         &5,
+        &default_options(&env),
     );
 
     client.submit_feedback(
@@ -446,7 +479,7 @@ fn test_update_submission_after_payout() {
         &token_address,
         &10,
     );
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &hunter, &None);
 
     // Should fail: already paid
     client.update_submission(&mission_id, &hunter, &String::from_str(&env, "QmNew"));
@@ -466,6 +499,7 @@ fn test_update_submission_not_found() {
         &token_address,
         &100,
         &5,
+        &default_options(&env),
     );
 
     // Try to update without submitting first
@@ -488,6 +522,7 @@ fn test_update_submission_mission_not_open() {
         &token_address,
         &100,
         &5,
+        &default_options(&env),
     );
 
     client.submit_feedback(
@@ -502,3 +537,2581 @@ fn test_update_submission_mission_not_open() {
     // Should fail: mission is paused
     client.update_submission(&mission_id, &hunter, &String::from_str(&env, "QmNew"));
 }
+
+#[test]
+fn test_stake_returned_on_payout() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Stake Return"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &25,
+    );
+    assert_eq!(client.get_stake(&mission_id, &hunter).unwrap().amount, 25);
+
+    client.payout_participant(&mission_id, &hunter, &None);
+
+    assert_eq!(token_client.balance(&hunter), 1000 + 100);
+    assert_eq!(client.get_stake(&mission_id, &hunter), None);
+}
+
+#[test]
+fn test_reject_spam_forfeits_stake_to_owner() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Spam"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSpam"),
+        &token_address,
+        &40,
+    );
+
+    let owner_balance_before = token_client.balance(&owner);
+    client.reject_spam(&mission_id, &hunter);
+
+    assert_eq!(token_client.balance(&owner), owner_balance_before + 40);
+    assert_eq!(token_client.balance(&hunter), 1000 - 40);
+    assert_eq!(client.get_stake(&mission_id, &hunter), None);
+}
+
+#[test]
+fn test_withdraw_stake_after_cancel() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Withdraw"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &30,
+    );
+    client.cancel_mission(&mission_id);
+
+    assert_eq!(client.withdraw_stake(&mission_id, &hunter), 30);
+    assert_eq!(token_client.balance(&hunter), 1000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+fn test_withdraw_stake_while_mission_open_fails() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Withdraw Open"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &30,
+    );
+    client.withdraw_stake(&mission_id, &hunter);
+}
+
+#[test]
+fn test_reject_submission_returns_stake() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Reject"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &1,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &20,
+    );
+    client.reject_submission(&mission_id, &hunter, &String::from_str(&env, "QmReason"));
+
+    assert_eq!(token_client.balance(&hunter), 1000);
+    assert_eq!(client.get_stake(&mission_id, &hunter), None);
+
+    // The slot is still free for another hunter
+    let other = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &other, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &other,
+        &String::from_str(&env, "QmOther"),
+        &token_address,
+        &20,
+    );
+    client.payout_participant(&mission_id, &other, &None);
+    assert_eq!(client.get_mission(&mission_id).participants_count, 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+fn test_payout_rejected_submission_fails() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Reject Then Pay"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    client.reject_submission(&mission_id, &hunter, &String::from_str(&env, "QmReason"));
+    client.payout_participant(&mission_id, &hunter, &None);
+}
+
+#[test]
+fn test_approve_then_claim_reward() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Approve"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    client.approve_submission(&mission_id, &hunter, &None);
+
+    // Nothing moves until the hunter claims
+    assert_eq!(token_client.balance(&hunter), 990);
+    assert_eq!(client.get_mission(&mission_id).participants_count, 1);
+
+    assert_eq!(client.claim_reward(&mission_id, &hunter), 100);
+    assert_eq!(token_client.balance(&hunter), 1100);
+}
+
+#[test]
+fn test_approved_reward_survives_cancel() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Approve Cancel"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &3,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    client.approve_submission(&mission_id, &hunter, &None);
+
+    let owner_balance_before = token_client.balance(&owner);
+    client.cancel_mission(&mission_id);
+    assert_eq!(token_client.balance(&owner), owner_balance_before + 200);
+
+    client.claim_reward(&mission_id, &hunter);
+    assert_eq!(token_client.balance(&hunter), 1100);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+fn test_claim_reward_without_approval_fails() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Early Claim"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    client.claim_reward(&mission_id, &hunter);
+}
+
+#[test]
+fn test_update_mission_status_follows_lifecycle() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Lifecycle"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    client.update_mission_status(&mission_id, &MissionStatus::Started);
+    assert_eq!(
+        client.get_mission(&mission_id).status,
+        MissionStatus::Started
+    );
+
+    assert_eq!(
+        client.try_update_mission_status(&mission_id, &MissionStatus::Open),
+        Err(Ok(QuidError::InvalidState))
+    );
+    assert_eq!(
+        client.try_update_mission_status(&mission_id, &MissionStatus::Completed),
+        Err(Ok(QuidError::InvalidState))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+fn test_cancelled_mission_cannot_reopen() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Reopen"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    client.cancel_mission(&mission_id);
+    client.update_mission_status(&mission_id, &MissionStatus::Open);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+fn test_pause_completed_mission_fails() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Pause Completed"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &1,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    client.payout_participant(&mission_id, &hunter, &None);
+    client.pause_mission(&mission_id);
+}
+
+#[test]
+fn test_resume_mission_restores_previous_status() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Resume"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    client.update_mission_status(&mission_id, &MissionStatus::Started);
+    client.pause_mission(&mission_id);
+
+    let paused = client.get_mission(&mission_id);
+    assert_eq!(paused.status, MissionStatus::Paused);
+    assert_eq!(paused.paused_from, MissionStatus::Started);
+
+    client.resume_mission(&mission_id);
+
+    assert_eq!(
+        client.get_mission(&mission_id).status,
+        MissionStatus::Started
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+fn test_resume_mission_not_paused_fails() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Resume Open"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    client.resume_mission(&mission_id);
+}
+
+#[test]
+fn test_payout_allowed_while_paused() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Paused Payout"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    client.pause_mission(&mission_id);
+    client.payout_participant(&mission_id, &hunter, &None);
+
+    assert_eq!(token_client.balance(&hunter), 1100);
+    assert_eq!(
+        client.get_mission(&mission_id).status,
+        MissionStatus::Paused
+    );
+}
+
+#[test]
+fn test_competition_accepts_submissions_beyond_slots() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Contest"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &1,
+        &MissionOptions {
+            slot_policy: SlotPolicy::Competition,
+            ..default_options(&env)
+        },
+    );
+
+    let hunter1 = Address::generate(&env);
+    let hunter2 = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter1, 1000);
+    mint_tokens_for_hunter(&env, &token_address, &hunter2, 1000);
+    let cid = String::from_str(&env, "QmVal");
+
+    client.submit_feedback(&mission_id, &hunter1, &cid, &token_address, &10);
+    client.submit_feedback(&mission_id, &hunter2, &cid, &token_address, &10);
+    assert_eq!(client.get_mission(&mission_id).submissions_count, 2);
+
+    client.payout_participant(&mission_id, &hunter2, &None);
+
+    let mission = client.get_mission(&mission_id);
+    assert_eq!(mission.participants_count, 1);
+    assert_eq!(mission.status, MissionStatus::Completed);
+}
+
+#[test]
+fn test_list_missions_pagination() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    for _ in 0..5 {
+        client.create_mission(
+            &owner,
+            &String::from_str(&env, "Board"),
+            &String::from_str(&env, "QmDesc"),
+            &token_address,
+            &100,
+            &1,
+            &default_options(&env),
+        );
+    }
+
+    let first_page = client.list_missions(&0, &2);
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page.get(0).unwrap().id, 1);
+    assert_eq!(first_page.get(1).unwrap().id, 2);
+
+    let last_page = client.list_missions(&4, &10);
+    assert_eq!(last_page.len(), 1);
+    assert_eq!(last_page.get(0).unwrap().id, 5);
+}
+
+#[test]
+fn test_list_missions_by_status_tracks_transitions() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    for _ in 0..3 {
+        client.create_mission(
+            &owner,
+            &String::from_str(&env, "Board"),
+            &String::from_str(&env, "QmDesc"),
+            &token_address,
+            &100,
+            &1,
+            &default_options(&env),
+        );
+    }
+    client.cancel_mission(&2);
+
+    let open = client.list_missions_by_status(&MissionStatus::Open, &0, &10);
+    assert_eq!(open.missions.len(), 2);
+    assert_eq!(open.missions.get(0).unwrap().id, 1);
+    assert_eq!(open.missions.get(1).unwrap().id, 3);
+    assert_eq!(open.next_cursor, None);
+
+    let open_after_first = client.list_missions_by_status(&MissionStatus::Open, &1, &10);
+    assert_eq!(open_after_first.missions.len(), 1);
+    assert_eq!(open_after_first.missions.get(0).unwrap().id, 3);
+
+    let cancelled = client.list_missions_by_status(&MissionStatus::Cancelled, &0, &10);
+    assert_eq!(cancelled.missions.len(), 1);
+    assert_eq!(cancelled.missions.get(0).unwrap().id, 2);
+}
+
+#[test]
+fn test_list_submissions_with_status_filter() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Review Queue"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    let hunter1 = Address::generate(&env);
+    let hunter2 = Address::generate(&env);
+    let hunter3 = Address::generate(&env);
+    for hunter in [&hunter1, &hunter2, &hunter3] {
+        mint_tokens_for_hunter(&env, &token_address, hunter, 1000);
+        client.submit_feedback(
+            &mission_id,
+            hunter,
+            &String::from_str(&env, "QmSub"),
+            &token_address,
+            &10,
+        );
+    }
+
+    client.payout_participant(&mission_id, &hunter2, &None);
+    client.reject_submission(&mission_id, &hunter3, &String::from_str(&env, "QmReason"));
+
+    let all = client.list_submissions(&mission_id, &None, &0, &10);
+    assert_eq!(all.submissions.len(), 3);
+    assert_eq!(all.submissions.get(0).unwrap().hunter, hunter1);
+    assert_eq!(all.next_cursor, None);
+
+    let first_page = client.list_submissions(&mission_id, &None, &0, &2);
+    assert_eq!(first_page.next_cursor, Some(2));
+    let second_page = client.list_submissions(&mission_id, &None, &2, &10);
+    assert_eq!(second_page.submissions.len(), 1);
+    assert_eq!(second_page.submissions.get(0).unwrap().hunter, hunter3);
+
+    let pending = client.list_submissions(&mission_id, &Some(SubmissionStatus::Pending), &0, &10);
+    assert_eq!(pending.submissions.len(), 1);
+    assert_eq!(pending.submissions.get(0).unwrap().hunter, hunter1);
+
+    let paid = client.list_submissions(&mission_id, &Some(SubmissionStatus::Paid), &0, &10);
+    assert_eq!(paid.submissions.len(), 1);
+    assert_eq!(paid.submissions.get(0).unwrap().hunter, hunter2);
+
+    let rejected = client.get_submission(&mission_id, &hunter3);
+    assert_eq!(rejected.status, SubmissionStatus::Rejected);
+    assert_eq!(
+        rejected.rejection_cid,
+        Some(String::from_str(&env, "QmReason"))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+fn test_get_submission_not_found() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "No Submission"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    client.get_submission(&mission_id, &Address::generate(&env));
+}
+
+#[test]
+fn test_missions_by_owner() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let other_owner = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &other_owner, 1000);
+
+    let first = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Mine"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &1,
+        &default_options(&env),
+    );
+    client.create_mission(
+        &other_owner,
+        &String::from_str(&env, "Theirs"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &1,
+        &default_options(&env),
+    );
+    let third = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Also Mine"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &1,
+        &default_options(&env),
+    );
+
+    let mine = client.missions_by_owner(&owner, &0, &10);
+    assert_eq!(mine.len(), 2);
+    assert_eq!(mine.get(0).unwrap().id, first);
+    assert_eq!(mine.get(1).unwrap().id, third);
+
+    let after_first = client.missions_by_owner(&owner, &1, &10);
+    assert_eq!(after_first.len(), 1);
+    assert_eq!(after_first.get(0).unwrap().id, third);
+}
+
+#[test]
+fn test_submissions_by_hunter() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let first = client.create_mission(
+        &owner,
+        &String::from_str(&env, "First"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+    let second = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Second"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    // Submit to the newer mission first; results come back in submission order
+    for mission_id in [second, first] {
+        client.submit_feedback(
+            &mission_id,
+            &hunter,
+            &String::from_str(&env, "QmSub"),
+            &token_address,
+            &10,
+        );
+    }
+    client.payout_participant(&second, &hunter, &None);
+
+    let entries = client.submissions_by_hunter(&hunter, &0, &10);
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries.get(0).unwrap(), (second, SubmissionStatus::Paid));
+    assert_eq!(entries.get(1).unwrap(), (first, SubmissionStatus::Pending));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+fn test_submit_after_deadline_fails() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Deadline"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &MissionOptions {
+            submission_deadline: Some(1_000),
+            review_deadline: Some(2_000),
+            ..default_options(&env)
+        },
+    );
+
+    env.ledger().set_timestamp(1_001);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmLate"),
+        &token_address,
+        &10,
+    );
+}
+
+#[test]
+fn test_expire_mission_pays_pending_and_refunds_owner() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Ghosted"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &3,
+        &MissionOptions {
+            submission_deadline: Some(1_000),
+            review_deadline: Some(2_000),
+            ..default_options(&env)
+        },
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+
+    let owner_balance_before = token_client.balance(&owner);
+    env.ledger().set_timestamp(2_001);
+    client.expire_mission(&mission_id, &10);
+
+    assert_eq!(token_client.balance(&hunter), 1100);
+    assert_eq!(token_client.balance(&owner), owner_balance_before + 200);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(
+        client.get_mission(&mission_id).status,
+        MissionStatus::Expired
+    );
+}
+
+#[test]
+fn test_expire_mission_refund_pending_policy() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Refund Pending"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &2,
+        &MissionOptions {
+            review_deadline: Some(2_000),
+            expiry_policy: ExpiryPolicy::RefundPending,
+            ..default_options(&env)
+        },
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+
+    env.ledger().set_timestamp(2_001);
+    client.expire_mission(&mission_id, &10);
+
+    assert_eq!(token_client.balance(&hunter), 1000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(
+        client.get_submission(&mission_id, &hunter).status,
+        SubmissionStatus::Rejected
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+fn test_expire_mission_before_review_deadline_fails() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Too Early"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &2,
+        &MissionOptions {
+            review_deadline: Some(2_000),
+            ..default_options(&env)
+        },
+    );
+
+    env.ledger().set_timestamp(1_500);
+    client.expire_mission(&mission_id, &10);
+}
+
+#[test]
+fn test_gated_mission_accepts_qualifying_hunter() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    let aqua = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    mint_tokens_for_hunter(&env, &aqua, &hunter, 500);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Gated"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &MissionOptions {
+            gating: vec![
+                &env,
+                GatingRule {
+                    token: aqua,
+                    min_balance: 500,
+                },
+            ],
+            ..default_options(&env)
+        },
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    assert_eq!(client.get_mission(&mission_id).submissions_count, 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+fn test_gated_mission_rejects_hunter_below_minimum() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    let aqua = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    mint_tokens_for_hunter(&env, &aqua, &hunter, 499);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Gated"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &MissionOptions {
+            gating: vec![
+                &env,
+                GatingRule {
+                    token: aqua,
+                    min_balance: 500,
+                },
+            ],
+            ..default_options(&env)
+        },
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+}
+
+#[test]
+fn test_reputation_tracks_payouts_and_rejections() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let first = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Paid"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+    let second = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Rejected"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    env.ledger().set_timestamp(100);
+    client.submit_feedback(
+        &first,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    client.payout_participant(&first, &hunter, &None);
+
+    env.ledger().set_timestamp(200);
+    client.submit_feedback(
+        &second,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    client.reject_submission(&second, &hunter, &String::from_str(&env, "QmReason"));
+
+    let profile = client.get_reputation(&hunter);
+    assert_eq!(profile.paid_count, 1);
+    assert_eq!(profile.rejected_count, 1);
+    assert_eq!(profile.total_earned.get(token_address).unwrap(), 100);
+    assert_eq!(profile.first_activity, 100);
+    assert_eq!(profile.last_activity, 200);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #20)")]
+fn test_min_reputation_blocks_new_hunters() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Veterans Only"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &MissionOptions {
+            min_reputation: 1,
+            ..default_options(&env)
+        },
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+}
+
+#[test]
+fn test_founder_stats() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let completed = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Completed"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &3,
+        &default_options(&env),
+    );
+    let cancelled = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Cancelled"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &1,
+        &default_options(&env),
+    );
+    client.cancel_mission(&cancelled);
+
+    // Review times of 10, 30 and 50 seconds
+    for delay in [10u64, 30, 50] {
+        let hunter = Address::generate(&env);
+        mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+        let submitted_at = env.ledger().timestamp();
+        client.submit_feedback(
+            &completed,
+            &hunter,
+            &String::from_str(&env, "QmSub"),
+            &token_address,
+            &10,
+        );
+        env.ledger().set_timestamp(submitted_at + delay);
+        client.payout_participant(&completed, &hunter, &None);
+    }
+
+    let stats = client.get_founder_stats(&owner);
+    assert_eq!(stats.missions_created, 2);
+    assert_eq!(stats.missions_completed, 1);
+    assert_eq!(stats.missions_cancelled, 1);
+    assert_eq!(stats.missions_expired, 0);
+    assert_eq!(stats.total_paid.get(token_address).unwrap(), 300);
+    assert_eq!(stats.median_review_time, 30);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+fn test_stake_with_wrong_token_fails() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    let worthless = env
+        .register_stellar_asset_contract_v2(hunter.clone())
+        .address();
+    mint_tokens_for_hunter(&env, &worthless, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Stake Token"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &worthless,
+        &1,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #22)")]
+fn test_stake_below_minimum_fails() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Min Stake"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &MissionOptions {
+            min_stake: 50,
+            ..default_options(&env)
+        },
+    );
+    assert_eq!(client.get_mission(&mission_id).min_stake, 50);
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &49,
+    );
+}
+
+#[test]
+fn test_zero_stake_mission() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let hunter = Address::generate(&env);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "No Stake"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &MissionOptions {
+            min_stake: 0,
+            ..default_options(&env)
+        },
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &0,
+    );
+    assert_eq!(client.get_stake(&mission_id, &hunter), None);
+
+    client.payout_participant(&mission_id, &hunter, &None);
+    assert_eq!(token_client.balance(&hunter), 100);
+}
+
+#[test]
+fn test_increase_capacity_and_reward() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Top Up"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &2,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    client.approve_submission(&mission_id, &hunter, &None);
+
+    client.increase_capacity(&mission_id, &3);
+    assert_eq!(token_client.balance(&contract_id), 10 + 100 * 5);
+
+    // Only the four unapproved slots are topped up
+    client.increase_reward(&mission_id, &150);
+    assert_eq!(token_client.balance(&contract_id), 10 + 100 + 150 * 4);
+
+    let mission = client.get_mission(&mission_id);
+    assert_eq!(mission.max_participants, 5);
+    assert_eq!(mission.reward_amount, 150);
+
+    // The approved hunter keeps the amount they were approved for
+    assert_eq!(client.claim_reward(&mission_id, &hunter), 100);
+
+    let owner_balance_before = token_client.balance(&owner);
+    client.cancel_mission(&mission_id);
+    assert_eq!(token_client.balance(&owner), owner_balance_before + 150 * 4);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_decrease_capacity_refunds_unused_slots() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Shrink"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    let owner_balance_before = token_client.balance(&owner);
+    client.decrease_capacity(&mission_id, &2);
+
+    assert_eq!(token_client.balance(&owner), owner_balance_before + 200);
+    assert_eq!(token_client.balance(&contract_id), 300);
+    assert_eq!(client.get_mission(&mission_id).max_participants, 3);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+fn test_decrease_capacity_below_reserved_slots_fails() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Shrink Reserved"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &2,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    client.decrease_capacity(&mission_id, &2);
+}
+
+#[test]
+fn test_escrow_ledger_matches_token_balance() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Ledger"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &4,
+        &default_options(&env),
+    );
+    assert_eq!(client.get_mission(&mission_id).escrow_balance, 400);
+
+    let paid = Address::generate(&env);
+    let approved = Address::generate(&env);
+    let pending = Address::generate(&env);
+    for hunter in [&paid, &approved, &pending] {
+        mint_tokens_for_hunter(&env, &token_address, hunter, 1000);
+        client.submit_feedback(
+            &mission_id,
+            hunter,
+            &String::from_str(&env, "QmSub"),
+            &token_address,
+            &10,
+        );
+    }
+
+    client.payout_participant(&mission_id, &paid, &None);
+    client.approve_submission(&mission_id, &approved, &None);
+    assert_eq!(client.get_mission(&mission_id).escrow_balance, 300);
+
+    client.cancel_mission(&mission_id);
+
+    // The approved reward and both outstanding stakes remain owed
+    assert_eq!(client.get_mission(&mission_id).escrow_balance, 100);
+    let report = client.check_solvency(&token_address);
+    assert_eq!(report.liabilities, 100 + 20);
+    assert_eq!(report.balance, token_client.balance(&contract_id));
+    assert!(report.solvent);
+
+    client.claim_reward(&mission_id, &approved);
+    client.withdraw_stake(&mission_id, &pending);
+
+    let report = client.check_solvency(&token_address);
+    assert_eq!(report.liabilities, 0);
+    assert_eq!(report.balance, 0);
+    assert_eq!(client.get_mission(&mission_id).escrow_balance, 0);
+}
+
+#[test]
+fn test_batch_payout_reports_per_hunter_results() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Batch"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &3,
+        &MissionOptions {
+            slot_policy: SlotPolicy::Competition,
+            ..default_options(&env)
+        },
+    );
+
+    let already_paid = Address::generate(&env);
+    let rejected = Address::generate(&env);
+    let fresh = Address::generate(&env);
+    let late = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    for hunter in [&already_paid, &rejected, &fresh, &late] {
+        mint_tokens_for_hunter(&env, &token_address, hunter, 1000);
+        client.submit_feedback(
+            &mission_id,
+            hunter,
+            &String::from_str(&env, "QmSub"),
+            &token_address,
+            &10,
+        );
+    }
+    client.payout_participant(&mission_id, &already_paid, &None);
+    client.reject_submission(&mission_id, &rejected, &String::from_str(&env, "QmNo"));
+
+    let hunters = vec![
+        &env,
+        already_paid.clone(),
+        rejected.clone(),
+        stranger.clone(),
+        fresh.clone(),
+        late.clone(),
+    ];
+    let results = client.batch_payout(&mission_id, &hunters);
+
+    let outcomes: Vec<PayoutOutcome> = Vec::from_iter(&env, results.iter().map(|r| r.outcome));
+    assert_eq!(
+        outcomes,
+        vec![
+            &env,
+            PayoutOutcome::AlreadyPaid,
+            PayoutOutcome::NotPending,
+            PayoutOutcome::SubmissionNotFound,
+            PayoutOutcome::Paid,
+            PayoutOutcome::Paid,
+        ]
+    );
+    assert_eq!(results.get(3).unwrap().hunter, fresh);
+    assert_eq!(token_client.balance(&fresh), 1100);
+    assert_eq!(token_client.balance(&late), 1100);
+    assert_eq!(
+        client.get_mission(&mission_id).status,
+        MissionStatus::Completed
+    );
+}
+
+#[test]
+fn test_batch_payout_stops_paying_once_full() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Batch Full"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &1,
+        &MissionOptions {
+            slot_policy: SlotPolicy::Competition,
+            ..default_options(&env)
+        },
+    );
+
+    let winner = Address::generate(&env);
+    let runner_up = Address::generate(&env);
+    for hunter in [&winner, &runner_up] {
+        mint_tokens_for_hunter(&env, &token_address, hunter, 1000);
+        client.submit_feedback(
+            &mission_id,
+            hunter,
+            &String::from_str(&env, "QmSub"),
+            &token_address,
+            &10,
+        );
+    }
+
+    let results = client.batch_payout(&mission_id, &vec![&env, winner, runner_up]);
+    assert_eq!(results.get(0).unwrap().outcome, PayoutOutcome::Paid);
+    assert_eq!(
+        results.get(1).unwrap().outcome,
+        PayoutOutcome::MissionClosed
+    );
+}
+
+#[test]
+fn test_custom_payout_amount_refunds_savings_on_cancel() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Custom Amount"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &3,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    client.payout_participant(&mission_id, &hunter, &Some(40));
+    assert_eq!(token_client.balance(&hunter), 1000 + 40);
+
+    let owner_balance_before = token_client.balance(&owner);
+    client.cancel_mission(&mission_id);
+    assert_eq!(
+        token_client.balance(&owner),
+        owner_balance_before + 300 - 40
+    );
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_completed_mission_refunds_savings() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Savings"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &1,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+
+    let owner_balance_before = token_client.balance(&owner);
+    client.approve_submission(&mission_id, &hunter, &Some(70));
+    assert_eq!(token_client.balance(&owner), owner_balance_before + 30);
+
+    assert_eq!(client.claim_reward(&mission_id, &hunter), 70);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_payout_above_cap_fails() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Over Cap"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &3,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    client.payout_participant(&mission_id, &hunter, &Some(101));
+}
+
+#[test]
+fn test_award_bonus() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Bonus"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &3,
+        &default_options(&env),
+    );
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    client.payout_participant(&mission_id, &hunter, &None);
+    client.award_bonus(&mission_id, &hunter, &25);
+
+    assert_eq!(token_client.balance(&hunter), 1000 + 100 + 25);
+    assert_eq!(client.get_submission(&mission_id, &hunter).bonus, 25);
+    assert_eq!(
+        client
+            .get_reputation(&hunter)
+            .total_earned
+            .get(token_address.clone())
+            .unwrap(),
+        125
+    );
+    assert!(client.check_solvency(&token_address).solvent);
+}
+
+fn create_contest(
+    env: &Env,
+    client: &QuidStoreContractClient,
+    owner: &Address,
+    token_address: &Address,
+) -> u64 {
+    client.create_mission(
+        owner,
+        &String::from_str(env, "Bug Contest"),
+        &String::from_str(env, "QmDesc"),
+        token_address,
+        &500,
+        &3,
+        &MissionOptions {
+            kind: MissionKind::Contest(vec![env, 500, 300, 100]),
+            ..default_options(env)
+        },
+    )
+}
+
+#[test]
+fn test_contest_finalize_rankings() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let owner_balance_before = token_client.balance(&owner);
+    let mission_id = create_contest(&env, &client, &owner, &token_address);
+    assert_eq!(token_client.balance(&contract_id), 900);
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let loser = Address::generate(&env);
+    for hunter in [&first, &second, &loser] {
+        mint_tokens_for_hunter(&env, &token_address, hunter, 1000);
+        client.submit_feedback(
+            &mission_id,
+            hunter,
+            &String::from_str(&env, "QmSub"),
+            &token_address,
+            &10,
+        );
+    }
+
+    client.finalize_rankings(&mission_id, &vec![&env, first.clone(), second.clone()]);
+
+    assert_eq!(token_client.balance(&first), 1000 + 500);
+    assert_eq!(token_client.balance(&second), 1000 + 300);
+    assert_eq!(token_client.balance(&owner), owner_balance_before - 800);
+    assert_eq!(
+        client.get_mission(&mission_id).status,
+        MissionStatus::Completed
+    );
+
+    client.withdraw_stake(&mission_id, &loser);
+    assert_eq!(token_client.balance(&loser), 1000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_contest_rejects_flat_payout() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    let mission_id = create_contest(&env, &client, &owner, &token_address);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+
+    assert_eq!(
+        client.try_payout_participant(&mission_id, &hunter, &None),
+        Err(Ok(QuidError::WrongMissionKind))
+    );
+}
+
+#[test]
+fn test_contest_prize_table_validation() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let result = client.try_create_mission(
+        &owner,
+        &String::from_str(&env, "Bad Contest"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &500,
+        &2,
+        &MissionOptions {
+            kind: MissionKind::Contest(vec![&env, 300, 500]),
+            ..default_options(&env)
+        },
+    );
+    assert_eq!(result, Err(Ok(QuidError::InvalidPrizeTable)));
+}
+
+#[test]
+fn test_multi_token_rewards() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let project_admin = Address::generate(&env);
+    let project_token = env
+        .register_stellar_asset_contract_v2(project_admin)
+        .address();
+    let project_client = TokenClient::new(&env, &project_token);
+    StellarAssetClient::new(&env, &project_token).mint(&owner, &1000);
+    client.add_allowed_token(&project_token);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Multi Token"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &3,
+        &MissionOptions {
+            extra_rewards: vec![
+                &env,
+                RewardComponent {
+                    token: project_token.clone(),
+                    amount: 50,
+                },
+            ],
+            ..default_options(&env)
+        },
+    );
+    assert_eq!(project_client.balance(&contract_id), 150);
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    client.payout_participant(&mission_id, &hunter, &None);
+
+    assert_eq!(token_client.balance(&hunter), 1000 + 100);
+    assert_eq!(project_client.balance(&hunter), 50);
+
+    client.cancel_mission(&mission_id);
+    assert_eq!(project_client.balance(&owner), 1000 - 50);
+    assert_eq!(project_client.balance(&contract_id), 0);
+    assert!(client.check_solvency(&project_token).solvent);
+}
+
+#[test]
+fn test_admin_two_step_transfer() {
+    let (env, contract_id, _owner, _token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let admin = client.get_admin();
+    assert_eq!(client.get_config().treasury, admin);
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(QuidError::NoPendingAdmin))
+    );
+
+    let new_admin = Address::generate(&env);
+    client.transfer_admin(&new_admin);
+    assert_eq!(client.get_admin(), admin);
+
+    client.accept_admin();
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(QuidError::NoPendingAdmin))
+    );
+
+    let treasury = Address::generate(&env);
+    client.set_config(&Config {
+        treasury: treasury.clone(),
+        fee_bps: 0,
+    });
+    assert_eq!(client.get_config().treasury, treasury);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_set_config_requires_admin() {
+    let (env, contract_id, _owner, _token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    env.mock_auths(&[]);
+    client.set_config(&Config {
+        treasury: Address::generate(&env),
+        fee_bps: 0,
+    });
+}
+
+#[test]
+fn test_payout_fee_goes_to_treasury() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let treasury = Address::generate(&env);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.set_config(&Config {
+        treasury: treasury.clone(),
+        fee_bps: 250,
+    });
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Fee"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &1000,
+        &2,
+        &default_options(&env),
+    );
+
+    // Raising the fee later doesn't touch missions that are already funded.
+    client.set_config(&Config {
+        treasury: treasury.clone(),
+        fee_bps: 1_000,
+    });
+    assert_eq!(client.get_mission(&mission_id).fee_bps, 250);
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    client.payout_participant(&mission_id, &hunter, &None);
+
+    assert_eq!(token_client.balance(&hunter), 1000 + 975);
+    assert_eq!(token_client.balance(&treasury), 25);
+    assert!(client.check_solvency(&token_address).solvent);
+}
+
+#[test]
+fn test_fee_above_cap_rejected() {
+    let (env, contract_id, _owner, _token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let result = client.try_set_config(&Config {
+        treasury: Address::generate(&env),
+        fee_bps: 1_001,
+    });
+    assert_eq!(result, Err(Ok(QuidError::InvalidAmount)));
+}
+
+#[test]
+fn test_migrate_pages_through_missions() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    for _ in 0..3 {
+        client.create_mission(
+            &owner,
+            &String::from_str(&env, "Migrate"),
+            &String::from_str(&env, "QmDesc"),
+            &token_address,
+            &100,
+            &2,
+            &default_options(&env),
+        );
+    }
+    client.submit_feedback(
+        &2,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    let mission = client.get_mission(&2);
+
+    assert_eq!(client.try_migrate(&10), Err(Ok(QuidError::InvalidState)));
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&DataKey::SchemaVersion, &0u32);
+    });
+
+    assert_eq!(client.migrate(&2), 2);
+    assert_eq!(client.get_schema_version(), 0);
+    assert_eq!(client.migrate(&2), 2);
+    assert_eq!(client.get_schema_version(), 1);
+    assert_eq!(client.try_migrate(&2), Err(Ok(QuidError::InvalidState)));
+
+    assert_eq!(client.get_mission(&2), mission);
+    assert_eq!(
+        client.get_submission(&2, &hunter).status,
+        SubmissionStatus::Pending
+    );
+}
+
+#[test]
+fn test_migrate_resumes_within_a_large_mission() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Crowded"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &1,
+        &60,
+        &MissionOptions {
+            min_stake: 0,
+            ..default_options(&env)
+        },
+    );
+    let mut hunters = Vec::new(&env);
+    for _ in 0..60 {
+        let hunter = Address::generate(&env);
+        client.submit_feedback(
+            &mission_id,
+            &hunter,
+            &String::from_str(&env, "QmSub"),
+            &token_address,
+            &0,
+        );
+        hunters.push_back(hunter);
+    }
+    let mission = client.get_mission(&mission_id);
+
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&DataKey::SchemaVersion, &0u32);
+    });
+
+    // 60 submissions plus the mission record take two capped calls.
+    assert_eq!(client.migrate(&100), 50);
+    assert_eq!(client.get_schema_version(), 0);
+    let progress: MigrationProgress = env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .get(&DataKey::MigrationCursor)
+            .unwrap()
+    });
+    assert_eq!(
+        progress,
+        MigrationProgress {
+            mission_id,
+            submission_pos: 50,
+        }
+    );
+
+    assert_eq!(client.migrate(&100), 11);
+    assert_eq!(client.get_schema_version(), 1);
+    assert_eq!(client.get_mission(&mission_id), mission);
+    for hunter in hunters.iter() {
+        assert_eq!(
+            client.get_submission(&mission_id, &hunter).status,
+            SubmissionStatus::Pending
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_upgrade_requires_admin() {
+    let (env, contract_id, _owner, _token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    env.mock_auths(&[]);
+    client.upgrade(&BytesN::from_array(&env, &[0; 32]));
+}
+
+#[test]
+fn test_global_pause_blocks_activity_but_not_exits() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let hunter = Address::generate(&env);
+    let approved_hunter = Address::generate(&env);
+    let late_hunter = Address::generate(&env);
+    let pay_hunter = Address::generate(&env);
+
+    for h in [&hunter, &approved_hunter, &late_hunter, &pay_hunter] {
+        mint_tokens_for_hunter(&env, &token_address, h, 1000);
+    }
+    env.ledger().set_timestamp(1_000);
+    let abandoned_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Abandoned"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &2,
+        &MissionOptions {
+            review_deadline: Some(2_000),
+            expiry_policy: ExpiryPolicy::RefundPending,
+            ..default_options(&env)
+        },
+    );
+    client.submit_feedback(
+        &abandoned_id,
+        &late_hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    let pay_pending_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Pay Pending"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &2,
+        &MissionOptions {
+            review_deadline: Some(2_000),
+            ..default_options(&env)
+        },
+    );
+    client.submit_feedback(
+        &pay_pending_id,
+        &pay_hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Frozen"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &2,
+        &default_options(&env),
+    );
+    for h in [&hunter, &approved_hunter] {
+        client.submit_feedback(
+            &mission_id,
+            h,
+            &String::from_str(&env, "QmSub"),
+            &token_address,
+            &10,
+        );
+    }
+    client.approve_submission(&mission_id, &approved_hunter, &None);
+
+    client.set_paused(&true);
+    assert!(client.is_paused());
+
+    assert_eq!(
+        client.try_create_mission(
+            &owner,
+            &String::from_str(&env, "Blocked"),
+            &String::from_str(&env, "QmDesc"),
+            &token_address,
+            &100,
+            &1,
+            &default_options(&env),
+        ),
+        Err(Ok(QuidError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_payout_participant(&mission_id, &hunter, &None),
+        Err(Ok(QuidError::ContractPaused))
+    );
+
+    assert_eq!(
+        client.try_increase_capacity(&mission_id, &1),
+        Err(Ok(QuidError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_increase_reward(&mission_id, &200),
+        Err(Ok(QuidError::ContractPaused))
+    );
+
+    client.cancel_mission(&mission_id);
+    client.withdraw_stake(&mission_id, &hunter);
+    assert_eq!(token_client.balance(&hunter), 1000);
+
+    // An approved hunter gets their stake back while the reward stays earmarked.
+    assert_eq!(
+        client.try_claim_reward(&mission_id, &approved_hunter),
+        Err(Ok(QuidError::ContractPaused))
+    );
+    client.withdraw_stake(&mission_id, &approved_hunter);
+    assert_eq!(token_client.balance(&approved_hunter), 1000);
+
+    // Refund-only expiry still runs; expiry that would pay out waits for the unpause.
+    env.ledger().set_timestamp(2_001);
+    assert!(client.expire_mission(&abandoned_id, &10));
+    assert_eq!(token_client.balance(&late_hunter), 1000);
+    assert_eq!(
+        client.try_expire_mission(&pay_pending_id, &10),
+        Err(Ok(QuidError::ContractPaused))
+    );
+
+    client.set_paused(&false);
+    assert!(!client.is_paused());
+
+    assert_eq!(client.claim_reward(&mission_id, &approved_hunter), 100);
+    assert!(client.expire_mission(&pay_pending_id, &10));
+    assert_eq!(token_client.balance(&pay_hunter), 1000 + 100);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_token_allow_list() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    assert_eq!(
+        client.list_allowed_tokens(&0, &10).tokens,
+        vec![&env, token_address.clone()]
+    );
+
+    let scam_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &scam_token).mint(&owner, &1000);
+    assert_eq!(
+        client.try_create_mission(
+            &owner,
+            &String::from_str(&env, "Fake USDC"),
+            &String::from_str(&env, "QmDesc"),
+            &scam_token,
+            &100,
+            &1,
+            &default_options(&env),
+        ),
+        Err(Ok(QuidError::TokenNotAllowed))
+    );
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Real USDC"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &1,
+        &default_options(&env),
+    );
+
+    client.remove_allowed_token(&token_address);
+    assert!(client.list_allowed_tokens(&0, &10).tokens.is_empty());
+    assert_eq!(
+        client.try_submit_feedback(
+            &mission_id,
+            &hunter,
+            &String::from_str(&env, "QmSub"),
+            &token_address,
+            &10,
+        ),
+        Err(Ok(QuidError::TokenNotAllowed))
+    );
+
+    // Missions already funded in a removed token can still be wound down.
+    client.cancel_mission(&mission_id);
+    assert_eq!(
+        TokenClient::new(&env, &token_address).balance(&contract_id),
+        0
+    );
+}
+
+#[test]
+fn test_cannot_reject_on_closed_mission() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Closed"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &2,
+        &default_options(&env),
+    );
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    client.cancel_mission(&mission_id);
+
+    assert_eq!(
+        client.try_reject_spam(&mission_id, &hunter),
+        Err(Ok(QuidError::MissionClosed))
+    );
+    assert_eq!(
+        client.try_reject_submission(&mission_id, &hunter, &String::from_str(&env, "QmWhy")),
+        Err(Ok(QuidError::MissionClosed))
+    );
+
+    client.withdraw_stake(&mission_id, &hunter);
+    assert_eq!(token_client.balance(&hunter), 1000);
+}
+
+#[test]
+fn test_batch_approve_reports_per_hunter_results() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Batch Approve"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &3,
+        &default_options(&env),
+    );
+    for hunter in [&first, &second] {
+        mint_tokens_for_hunter(&env, &token_address, hunter, 1000);
+        client.submit_feedback(
+            &mission_id,
+            hunter,
+            &String::from_str(&env, "QmSub"),
+            &token_address,
+            &10,
+        );
+    }
+
+    let results = client.batch_approve(
+        &mission_id,
+        &vec![
+            &env,
+            first.clone(),
+            first.clone(),
+            stranger.clone(),
+            second.clone(),
+        ],
+    );
+    let outcomes: Vec<PayoutOutcome> = Vec::from_iter(&env, results.iter().map(|r| r.outcome));
+    assert_eq!(
+        outcomes,
+        vec![
+            &env,
+            PayoutOutcome::Approved,
+            PayoutOutcome::NotPending,
+            PayoutOutcome::SubmissionNotFound,
+            PayoutOutcome::Approved,
+        ]
+    );
+
+    assert_eq!(client.claim_reward(&mission_id, &first), 100);
+    assert_eq!(client.claim_reward(&mission_id, &second), 100);
+}
+
+#[test]
+fn test_filtered_submission_cursor_is_stable() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Stable Cursor"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &5,
+        &default_options(&env),
+    );
+
+    let hunters = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for hunter in hunters.iter() {
+        mint_tokens_for_hunter(&env, &token_address, hunter, 1000);
+        client.submit_feedback(
+            &mission_id,
+            hunter,
+            &String::from_str(&env, "QmSub"),
+            &token_address,
+            &10,
+        );
+    }
+
+    let pending = Some(SubmissionStatus::Pending);
+    let first_page = client.list_submissions(&mission_id, &pending, &0, &2);
+    assert_eq!(first_page.submissions.len(), 2);
+
+    // Settling a submission from the first page must not make the next page skip anyone.
+    client.approve_submission(&mission_id, &hunters[0], &None);
+
+    let second_page =
+        client.list_submissions(&mission_id, &pending, &first_page.next_cursor.unwrap(), &2);
+    assert_eq!(second_page.submissions.len(), 2);
+    assert_eq!(second_page.submissions.get(0).unwrap().hunter, hunters[2]);
+    assert_eq!(second_page.submissions.get(1).unwrap().hunter, hunters[3]);
+    assert_eq!(second_page.next_cursor, None);
+}
+
+#[test]
+fn test_status_index_pages_across_buckets() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    for _ in 0..60 {
+        client.create_mission(
+            &owner,
+            &String::from_str(&env, "Board"),
+            &String::from_str(&env, "QmDesc"),
+            &token_address,
+            &1,
+            &1,
+            &default_options(&env),
+        );
+    }
+    for id in [2, 51, 52] {
+        client.cancel_mission(&id);
+    }
+
+    let mut open_ids = Vec::new(&env);
+    let mut cursor = Some(0);
+    while let Some(position) = cursor {
+        let page = client.list_missions_by_status(&MissionStatus::Open, &position, &20);
+        for mission in page.missions.iter() {
+            open_ids.push_back(mission.id);
+        }
+        cursor = page.next_cursor;
+    }
+    assert_eq!(open_ids.len(), 57);
+    assert_eq!(open_ids.get(1).unwrap(), 3);
+    assert_eq!(open_ids.get(49).unwrap(), 53);
+
+    let cancelled = client.list_missions_by_status(&MissionStatus::Cancelled, &0, &50);
+    assert_eq!(cancelled.missions.len(), 3);
+    assert_eq!(cancelled.next_cursor, None);
+}
+
+#[test]
+fn test_expire_mission_in_pages() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    env.ledger().set_timestamp(1_000);
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Abandoned"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &2,
+        &MissionOptions {
+            slot_policy: SlotPolicy::Competition,
+            review_deadline: Some(2_000),
+            expiry_policy: ExpiryPolicy::RefundPending,
+            ..default_options(&env)
+        },
+    );
+
+    let mut hunters = Vec::new(&env);
+    for _ in 0..5 {
+        let hunter = Address::generate(&env);
+        mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+        client.submit_feedback(
+            &mission_id,
+            &hunter,
+            &String::from_str(&env, "QmSub"),
+            &token_address,
+            &10,
+        );
+        hunters.push_back(hunter);
+    }
+
+    env.ledger().set_timestamp(2_001);
+    assert!(!client.expire_mission(&mission_id, &2));
+    assert!(!client.expire_mission(&mission_id, &2));
+    assert_eq!(client.get_mission(&mission_id).status, MissionStatus::Open);
+    assert!(client.expire_mission(&mission_id, &2));
+    assert_eq!(
+        client.get_mission(&mission_id).status,
+        MissionStatus::Expired
+    );
+
+    for hunter in hunters.iter() {
+        assert_eq!(token_client.balance(&hunter), 1000);
+    }
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_median_review_time_uses_recent_window() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let fast = reputation::REVIEW_TIME_WINDOW;
+    let slow = 40;
+    env.ledger().set_timestamp(1_000);
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Busy Founder"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &1,
+        &(fast + slow),
+        &MissionOptions {
+            min_stake: 0,
+            ..default_options(&env)
+        },
+    );
+
+    let mut hunters = Vec::new(&env);
+    for _ in 0..fast + slow {
+        let hunter = Address::generate(&env);
+        client.submit_feedback(
+            &mission_id,
+            &hunter,
+            &String::from_str(&env, "QmSub"),
+            &token_address,
+            &0,
+        );
+        hunters.push_back(hunter);
+    }
+
+    env.ledger().set_timestamp(1_010);
+    for hunter in hunters.slice(0..fast).iter() {
+        client.approve_submission(&mission_id, &hunter, &None);
+    }
+    assert_eq!(client.get_founder_stats(&owner).median_review_time, 10);
+
+    // Older fast reviews fall out of the window as slow ones arrive.
+    env.ledger().set_timestamp(2_000);
+    for hunter in hunters.slice(fast..).iter() {
+        client.approve_submission(&mission_id, &hunter, &None);
+    }
+    assert_eq!(client.get_founder_stats(&owner).median_review_time, 1_000);
+}
+
+#[test]
+fn test_zero_stake_mission_ignores_delisted_stake_token() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    let stake_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_allowed_token(&stake_token);
+
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "No Stake"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &1,
+        &MissionOptions {
+            stake_token: Some(stake_token.clone()),
+            min_stake: 0,
+            ..default_options(&env)
+        },
+    );
+    client.remove_allowed_token(&stake_token);
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &stake_token,
+        &0,
+    );
+    assert_eq!(
+        client.get_submission(&mission_id, &hunter).status,
+        SubmissionStatus::Pending
+    );
+}
+
+#[test]
+fn test_submit_after_review_deadline_fails() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    env.ledger().set_timestamp(1_000);
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Review Only"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &2,
+        &MissionOptions {
+            review_deadline: Some(2_000),
+            ..default_options(&env)
+        },
+    );
+
+    env.ledger().set_timestamp(2_001);
+    assert_eq!(
+        client.try_submit_feedback(
+            &mission_id,
+            &hunter,
+            &String::from_str(&env, "QmJunk"),
+            &token_address,
+            &10,
+        ),
+        Err(Ok(QuidError::SubmissionDeadlinePassed))
+    );
+}
+
+#[test]
+fn test_update_approved_submission_fails() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let hunter = Address::generate(&env);
+
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    let mission_id = client.create_mission(
+        &owner,
+        &String::from_str(&env, "Reviewed"),
+        &String::from_str(&env, "QmDesc"),
+        &token_address,
+        &100,
+        &2,
+        &default_options(&env),
+    );
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &token_address,
+        &10,
+    );
+    client.approve_submission(&mission_id, &hunter, &None);
+
+    assert_eq!(
+        client.try_update_submission(&mission_id, &hunter, &String::from_str(&env, "QmSwap")),
+        Err(Ok(QuidError::NotPending))
+    );
+    assert_eq!(
+        client.get_submission(&mission_id, &hunter).ipfs_cid,
+        String::from_str(&env, "QmSub")
+    );
+}
+
+#[test]
+fn test_allowed_tokens_page_and_relist() {
+    let (env, contract_id, _owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+    client.add_allowed_token(&second);
+    client.add_allowed_token(&third);
+    client.remove_allowed_token(&second);
+
+    let first_page = client.list_allowed_tokens(&0, &2);
+    assert_eq!(first_page.tokens, vec![&env, token_address.clone()]);
+    assert_eq!(first_page.next_cursor, Some(2));
+    let second_page = client.list_allowed_tokens(&2, &2);
+    assert_eq!(second_page.tokens, vec![&env, third.clone()]);
+    assert_eq!(second_page.next_cursor, None);
+
+    // Re-listing a token flips it back on without adding a second index entry.
+    client.add_allowed_token(&second);
+    client.add_allowed_token(&second);
+    let all = client.list_allowed_tokens(&0, &10);
+    assert_eq!(all.tokens, vec![&env, token_address, second, third]);
+    assert_eq!(all.next_cursor, None);
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, Map, String, Vec};
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Copy)]
 #[contracttype]
@@ -10,6 +10,40 @@ pub enum MissionStatus {
     Paused,
     Completed,
     Cancelled,
+    Expired,
+}
+
+impl MissionStatus {
+    /// The mission lifecycle: Created -> Open -> Started, with Paused reachable from
+    /// either live status and returning to it. Completed, Cancelled and Expired are terminal.
+    pub fn can_transition_to(self, next: MissionStatus) -> bool {
+        use MissionStatus::*;
+        matches!(
+            (self, next),
+            (Created, Open)
+                | (Created, Cancelled)
+                | (Open, Started)
+                | (Open, Paused)
+                | (Open, Completed)
+                | (Open, Cancelled)
+                | (Started, Paused)
+                | (Started, Completed)
+                | (Started, Cancelled)
+                | (Paused, Open)
+                | (Paused, Started)
+                | (Paused, Completed)
+                | (Paused, Cancelled)
+                | (Created | Open | Started | Paused, Expired)
+        )
+    }
+
+    /// Closed missions accept no further submissions or payouts.
+    pub fn is_closed(self) -> bool {
+        matches!(
+            self,
+            MissionStatus::Completed | MissionStatus::Cancelled | MissionStatus::Expired
+        )
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Copy)]
@@ -22,6 +56,76 @@ pub enum SubmissionStatus {
     Rejected,
 }
 
+/// How `max_participants` limits who can take part in a mission.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Copy)]
+#[contracttype]
+pub enum SlotPolicy {
+    /// Each submission reserves one of the slots, first come first served.
+    #[default]
+    FirstCome,
+    /// Anyone can submit; `max_participants` caps how many winners get paid.
+    Competition,
+}
+
+/// What `expire_mission` does with submissions still pending at the review deadline.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Copy)]
+#[contracttype]
+pub enum ExpiryPolicy {
+    /// Pay pending submissions in submission order while slots remain.
+    #[default]
+    PayPending,
+    /// Reject pending submissions and return their stakes.
+    RefundPending,
+}
+
+/// How a mission's escrow is shared out between hunters.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[contracttype]
+pub enum MissionKind {
+    /// Every approved submission earns up to `reward_amount`.
+    #[default]
+    Bounty,
+    /// Ranked prizes, best first, paid out by `finalize_rankings`. Contests always use
+    /// `SlotPolicy::Competition` and have one slot per prize.
+    Contest(Vec<i128>),
+}
+
+/// A reward paid on top of the mission's `reward_token` to every winning submission.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardComponent {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// A hunter must hold at least `min_balance` of `token` to submit to a gated mission.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GatingRule {
+    pub token: Address,
+    pub min_balance: i128,
+}
+
+/// Settings chosen by the founder when creating a mission.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MissionOptions {
+    pub slot_policy: SlotPolicy,
+    pub submission_deadline: Option<u64>,
+    pub review_deadline: Option<u64>,
+    pub expiry_policy: ExpiryPolicy,
+    pub gating: Vec<GatingRule>,
+    /// Minimum number of paid submissions a hunter needs before submitting.
+    pub min_reputation: u32,
+    /// Token hunters stake in; defaults to the reward token.
+    pub stake_token: Option<Address>,
+    /// Smallest accepted stake. Zero turns staking off for the mission.
+    pub min_stake: i128,
+    pub kind: MissionKind,
+    /// Extra tokens paid alongside the primary reward. Bounties only.
+    pub extra_rewards: Vec<RewardComponent>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Mission {
@@ -33,8 +137,29 @@ pub struct Mission {
     pub reward_amount: i128,
     pub max_participants: u32,
     pub participants_count: u32,
+    /// Submissions currently holding a slot: pending, approved or paid.
+    pub submissions_count: u32,
+    pub slot_policy: SlotPolicy,
+    pub submission_deadline: Option<u64>,
+    pub review_deadline: Option<u64>,
+    pub expiry_policy: ExpiryPolicy,
+    pub gating: Vec<GatingRule>,
+    pub min_reputation: u32,
+    pub stake_token: Address,
+    pub min_stake: i128,
+    pub kind: MissionKind,
+    /// Extra rewards per slot; their escrow is held on top of `escrow_balance`.
+    pub extra_rewards: Vec<RewardComponent>,
+    /// Platform fee in basis points, fixed when the mission is created.
+    pub fee_bps: u32,
+    /// Reward tokens held for this mission: unallocated slots plus approved, unclaimed rewards.
+    pub escrow_balance: i128,
+    /// Part of `escrow_balance` approved for hunters but not yet claimed.
+    pub earmarked: i128,
     pub status: MissionStatus,
     pub created_at: u64,
+    /// Status to restore on resume; only meaningful while `status` is `Paused`.
+    pub paused_from: MissionStatus,
 }
 
 #[contracttype]
@@ -44,6 +169,133 @@ pub struct Submission {
     pub ipfs_cid: String,
     pub status: SubmissionStatus,
     pub submitted_at: u64,
+    pub rejection_cid: Option<String>,
+    pub reward_amount: i128,
+    pub bonus: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Stake {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// A hunter's track record across all missions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HunterProfile {
+    pub paid_count: u32,
+    pub rejected_count: u32,
+    /// Rewards received, keyed by token.
+    pub total_earned: Map<Address, i128>,
+    pub first_activity: u64,
+    pub last_activity: u64,
+}
+
+/// A founder's escrow track record across all of their missions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FounderStats {
+    pub missions_created: u32,
+    pub missions_completed: u32,
+    pub missions_cancelled: u32,
+    pub missions_expired: u32,
+    /// Rewards paid to hunters, keyed by token.
+    pub total_paid: Map<Address, i128>,
+    /// Median seconds from `submitted_at` until the founder approved or paid the submission,
+    /// over their most recent `REVIEW_TIME_WINDOW` reviews.
+    pub median_review_time: u64,
+}
+
+/// Why a hunter in a `batch_payout` or `batch_approve` was or wasn't settled.
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+#[contracttype]
+pub enum PayoutOutcome {
+    Paid,
+    SubmissionNotFound,
+    AlreadyPaid,
+    NotPending,
+    MissionClosed,
+    Approved,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutResult {
+    pub hunter: Address,
+    pub outcome: PayoutOutcome,
+}
+
+/// A page of `list_missions_by_status` results. `next_cursor` is where the following
+/// page starts, or `None` once the index has been read to the end.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MissionPage {
+    pub missions: Vec<Mission>,
+    pub next_cursor: Option<u32>,
+}
+
+/// A page of `list_allowed_tokens` results, with `next_cursor` as in `MissionPage`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenPage {
+    pub tokens: Vec<Address>,
+    pub next_cursor: Option<u32>,
+}
+
+/// A page of `list_submissions` results. `next_cursor` is where the following page
+/// starts, or `None` once the mission's submissions have all been read.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubmissionPage {
+    pub submissions: Vec<Submission>,
+    pub next_cursor: Option<u32>,
+}
+
+/// Where a `migrate` still in progress resumes: the mission being rewritten and the
+/// position of its next submission. The mission record itself is rewritten once its
+/// submissions are done.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationProgress {
+    pub mission_id: u64,
+    pub submission_pos: u32,
+}
+
+/// The contract's holdings of a token compared with what it owes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SolvencyReport {
+    pub liabilities: i128,
+    pub balance: i128,
+    pub solvent: bool,
+}
+
+/// Platform-wide settings, changed only by the admin.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Config {
+    /// Receives protocol revenue.
+    pub treasury: Address,
+    /// Fee taken from each primary reward payout, in basis points.
+    pub fee_bps: u32,
+}
+
+/// The append-only lists kept by `index`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IndexKey {
+    /// Ids of missions in a status, in the order they entered it.
+    Status(MissionStatus),
+    /// Hunters in submission order.
+    MissionSubmissions(u64),
+    /// Ids of an owner's missions in creation order.
+    OwnerMissions(Address),
+    /// Ids of the missions a hunter submitted to, in submission order.
+    HunterSubmissions(Address),
+    /// Every token ever allowed, in the order it was first listed.
+    AllowedTokens,
 }
 
 #[contracttype]
@@ -52,4 +304,22 @@ pub enum DataKey {
     MissionCount,
     Submission(u64, Address),
     HunterStake(u64, Address),
+    IndexLen(IndexKey),
+    IndexBucket(IndexKey, u32),
+    /// Position of a mission in the index of its current status.
+    StatusPos(u64),
+    /// Submissions already settled by an `expire_mission` still in progress.
+    ExpiryCursor(u64),
+    HunterProfile(Address),
+    FounderStats(Address),
+    /// A founder's most recent review times, oldest first.
+    FounderReviewTimes(Address),
+    Liabilities(Address),
+    Admin,
+    PendingAdmin,
+    Config,
+    SchemaVersion,
+    MigrationCursor,
+    Paused,
+    AllowedToken(Address),
 }